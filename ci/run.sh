@@ -85,6 +85,19 @@ else
     cargo_test_impl --release --features=into_bits
 fi
 
+# The golden outputs of the deterministic math mode must be reproduced on all
+# targets with IEEE 754 floating-point arithmetic:
+if [[ "${TARGET}" != "i586-unknown-linux-gnu" ]] && [[ "${TARGET}" != thumbv7neon-* ]]; then
+    cargo_test --release --features=deterministic_math --test deterministic_math
+fi
+
+# The exhaustive tests of the error bounds of the `f32` math functions take a
+# few minutes, and only run on one target:
+if [[ "${TARGET}" == "x86_64-unknown-linux-gnu" ]] && [[ "${RUSTFLAGS}" == *"+avx2"* ]] && [[ "${NORUN}" != "1" ]]; then
+    cargo test --verbose --target=${TARGET} --release \
+          --test exhaustive_f32_math -- --quiet --ignored
+fi
+
 # Verify code generation
 if [[ "${NOVERIFY}" != "1" ]]; then
     cp -r verify/verify target/verify
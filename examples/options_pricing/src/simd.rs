@@ -69,8 +69,10 @@ mod tests {
         let simd_sum = simd.exec(black_scholes);
         let scalar_sum = scalar.exec(crate::scalar::black_scholes);
 
-        assert_eq!(simd, scalar);
-        assert_eq!(simd_sum, scalar_sum);
+        for (a, b) in simd.result.iter().zip(&scalar.result) {
+            assert!(almost_equal(f64::from(*a), f64::from(*b), 1e-5));
+        }
+        assert!(almost_equal(simd_sum, scalar_sum, 1e-5));
     }
 
     #[test]
@@ -0,0 +1,84 @@
+//! Benchmarks for the vertical floating-point math functions `sin`, `cos`,
+//! `exp`, `ln`, `powf` and `tanh`, and the `_fast` variants of `sin`, `cos`,
+//! `exp` and `ln`, compared against applying the scalar `std` function to
+//! each lane.
+#![deny(rust_2018_idioms)]
+#![feature(test)]
+
+use packed_simd::*;
+use test::black_box;
+
+use criterion::{Benchmark, Criterion, Throughput};
+const NO_ITERATIONS: u32 = 1_000;
+
+macro_rules! bench_fn {
+    ($id:ident[$elem_ty:ident], $fun:ident, $x:expr, |$v:ident| $vector:expr, |$s:ident| $scalar:expr) => {
+        paste::item! {
+            fn [<$id _ $fun>](c: &mut Criterion) {
+                c.bench(
+                    stringify!($id),
+                    Benchmark::new(concat!(stringify!($fun), "_vector"), |b| b.iter(|| {
+                        let x = $id::splat($x);
+                        for _ in 0..NO_ITERATIONS {
+                            let $v = black_box(x);
+                            black_box($vector);
+                        }
+                    }))
+                    .with_function(concat!(stringify!($fun), "_scalar"), |b| b.iter(|| {
+                        let x = $id::splat($x);
+                        for _ in 0..NO_ITERATIONS {
+                            let x = black_box(x);
+                            let mut r = x;
+                            for i in 0..$id::lanes() {
+                                let $s = x.extract(i);
+                                r = r.replace(i, $scalar);
+                            }
+                            black_box(r);
+                        }
+                    }))
+                    .throughput(Throughput::Elements(NO_ITERATIONS * $id::lanes() as u32))
+                );
+            }
+        }
+    };
+}
+
+macro_rules! bench {
+    ($id:ident[$elem_ty:ident]) => {
+        bench_fn!($id[$elem_ty], sin, 0.5, |x| x.sin(), |x| x.sin());
+        bench_fn!($id[$elem_ty], sin_fast, 0.5, |x| x.sin_fast(), |x| x.sin());
+        bench_fn!($id[$elem_ty], cos, 0.5, |x| x.cos(), |x| x.cos());
+        bench_fn!($id[$elem_ty], cos_fast, 0.5, |x| x.cos_fast(), |x| x.cos());
+        bench_fn!($id[$elem_ty], exp, 0.5, |x| x.exp(), |x| x.exp());
+        bench_fn!($id[$elem_ty], exp_fast, 0.5, |x| x.exp_fast(), |x| x.exp());
+        bench_fn!($id[$elem_ty], ln, 1.5, |x| x.ln(), |x| x.ln());
+        bench_fn!($id[$elem_ty], ln_fast, 1.5, |x| x.ln_fast(), |x| x.ln());
+        bench_fn!(
+            $id[$elem_ty], powf, 1.5,
+            |x| x.powf($id::splat(0.75)), |x| x.powf(0.75)
+        );
+        bench_fn!($id[$elem_ty], tanh, 0.5, |x| x.tanh(), |x| x.tanh());
+    };
+    ($($id:ident[$elem_ty:ident]),*) => {
+        $( bench!($id[$elem_ty]); )*
+        paste::item! {
+            criterion_group!(
+                benches,
+                $([<$id _sin>]),*, $([<$id _sin_fast>]),*,
+                $([<$id _cos>]),*, $([<$id _cos_fast>]),*,
+                $([<$id _exp>]),*, $([<$id _exp_fast>]),*,
+                $([<$id _ln>]),*, $([<$id _ln_fast>]),*,
+                $([<$id _powf>]),*, $([<$id _tanh>]),*
+            );
+        }
+    };
+}
+
+bench!(
+    f32x2[f32], // 64-bit wide types
+    f32x4[f32], f64x2[f64], // 128-bit wide types
+    f32x8[f32], f64x4[f64], // 256-bit wide types
+    f32x16[f32], f64x8[f64] // 512-bit wide types
+);
+
+criterion_main!(benches);
@@ -3,6 +3,8 @@
 #[macro_use]
 mod bitmask;
 crate mod cast;
+crate mod divisor;
+mod split_concat;
 #[macro_use]
 mod cmp;
 #[macro_use]
@@ -40,8 +42,9 @@ mod bit_manip;
 crate mod into_bits;
 
 macro_rules! impl_i {
-    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident
-     | $ielem_ty:ident, $ibitmask_ty:ident | $test_tt:tt | $($elem_ids:ident),*
+    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident,
+     $utuple_id:ident | $ielem_ty:ident, $ibitmask_ty:ident | $test_tt:tt
+     | $($elem_ids:ident),*
      | From: $($from_vec_ty:ident),* | $(#[$doc:meta])*) => {
         impl_minimal_iuf!([$elem_ty; $elem_n]: $tuple_id | $ielem_ty | $test_tt
                           | $($elem_ids),* | $(#[$doc])*);
@@ -56,7 +59,21 @@ macro_rules! impl_i {
         impl_ops_vector_shifts!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_ops_scalar_shifts!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_ops_vector_rotates!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_funnel_shifts!(
+            [$elem_ty; $elem_n]: $tuple_id, $tuple_id | $test_tt
+        );
+        impl_ops_vector_saturating!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_overflowing!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty | $test_tt
+        );
+        impl_ops_vector_mul_high!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_abs_diff!(
+            [$elem_ty; $elem_n]: $tuple_id, $utuple_id | $test_tt
+        );
         impl_ops_vector_neg!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_int_sign!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty, $utuple_id | $test_tt
+        );
         impl_ops_vector_int_min_max!(
             [$elem_ty; $elem_n]: $tuple_id | $test_tt
         );
@@ -104,8 +121,9 @@ macro_rules! impl_i {
 }
 
 macro_rules! impl_u {
-    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident
-     | $ielem_ty:ident, $ibitmask_ty:ident | $test_tt:tt | $($elem_ids:ident),*
+    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident,
+     $ituple_id:ident | $ielem_ty:ident, $ibitmask_ty:ident | $test_tt:tt
+     | $($elem_ids:ident),*
      | From: $($from_vec_ty:ident),* | $(#[$doc:meta])*) => {
         impl_minimal_iuf!([$elem_ty; $elem_n]: $tuple_id | $ielem_ty | $test_tt
                           | $($elem_ids),* | $(#[$doc])*);
@@ -120,6 +138,17 @@ macro_rules! impl_u {
         impl_ops_vector_shifts!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_ops_scalar_shifts!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_ops_vector_rotates!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_funnel_shifts!(
+            [$elem_ty; $elem_n]: $tuple_id, $ituple_id | $test_tt
+        );
+        impl_ops_vector_saturating!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_overflowing!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty | $test_tt
+        );
+        impl_ops_vector_mul_high!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_ops_vector_abs_diff!(
+            [$elem_ty; $elem_n]: $tuple_id, $tuple_id | $test_tt
+        );
         impl_ops_vector_int_min_max!(
             [$elem_ty; $elem_n]: $tuple_id | $test_tt
         );
@@ -148,6 +177,9 @@ macro_rules! impl_u {
         impl_slice_write_to_slice!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_swap_bytes!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_bit_manip!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_bit_manip_pow2!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty | $test_tt
+        );
         impl_shuffle1_dyn!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_cmp_partial_eq!(
             [$elem_ty; $elem_n]: $tuple_id | $test_tt | (1, 0)
@@ -168,8 +200,8 @@ macro_rules! impl_u {
 }
 
 macro_rules! impl_f {
-    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident
-     | $ielem_ty:ident | $test_tt:tt | $($elem_ids:ident),*
+    ([$elem_ty:ident; $elem_n:expr]: $tuple_id:ident, $mask_ty:ident,
+     $ituple_id:ident, $utuple_id:ident | $ielem_ty:ident | $test_tt:tt | $($elem_ids:ident),*
      | From: $($from_vec_ty:ident),* | $(#[$doc:meta])*) => {
         impl_minimal_iuf!([$elem_ty; $elem_n]: $tuple_id | $ielem_ty | $test_tt
                           | $($elem_ids),* | $(#[$doc])*);
@@ -179,11 +211,17 @@ macro_rules! impl_f {
         impl_ops_vector_float_min_max!(
             [$elem_ty; $elem_n]: $tuple_id | $test_tt
         );
+        impl_ops_vector_float_sign!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty, $utuple_id | $test_tt
+        );
         impl_reduction_float_arithmetic!(
             [$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_reduction_min_max!(
             [$elem_ty; $elem_n]: $tuple_id | $ielem_ty | $test_tt
         );
+        impl_reduction_float_minimum_maximum!(
+            [$elem_ty; $elem_n]: $tuple_id | $test_tt
+        );
         impl_fmt_debug!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_from_array!([$elem_ty; $elem_n]: $tuple_id | $test_tt | (1., 1.));
         impl_from_vectors!(
@@ -202,22 +240,44 @@ macro_rules! impl_f {
 
         // floating-point math
         impl_math_float_abs!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_acos!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_acosh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_asin!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_asinh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_atan!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_atanh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_cbrt!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_cos!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_cosh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_erf!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_exp!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_frexp!(
+            [$elem_ty; $elem_n]: $tuple_id, $ituple_id | $test_tt
+        );
+        impl_math_float_gamma!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_hypot!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_ln!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_mul_add!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_mul_adde!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_powf!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_recpre!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_rsqrte!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_round!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_sin!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_sinh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_sqrt!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_sqrte!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
         impl_math_float_tanh!([$elem_ty; $elem_n]: $tuple_id | $test_tt);
+        impl_math_float_ulp!(
+            [$elem_ty; $elem_n]: $tuple_id, $ituple_id | $test_tt
+        );
         impl_cmp_vertical!(
             [$elem_ty; $elem_n]: $tuple_id, $mask_ty, false, (1., 0.)
                 | $test_tt
         );
+        impl_cmp_float_total_ord!(
+            [$elem_ty; $elem_n]: $tuple_id, $mask_ty, $utuple_id | $test_tt
+        );
 
         test_select!($elem_ty, $mask_ty, $tuple_id, (1., 2.) | $test_tt);
         test_reduction_float_min_max!(
@@ -17,6 +17,30 @@ macro_rules! impl_bit_manip {
                 super::codegen::bit_manip::BitManip::ctpop(!self)
             }
 
+            /// Returns the number of ones in the binary representation of
+            /// each byte of the lanes of `self`.
+            ///
+            /// Each byte of the result holds the count of the corresponding
+            /// byte of `self`, which is what the rank and select queries of
+            /// succinct data structures need. For 8-bit lanes this is the
+            /// same as [`count_ones`](#method.count_ones).
+            #[inline]
+            pub fn count_ones_per_byte(self) -> Self {
+                // Repeats the byte `b` in all the bytes of the lanes:
+                let bytes = |b: $elem_ty| {
+                    let mut m: $elem_ty = 0;
+                    for _ in 0..crate::mem::size_of::<$elem_ty>() {
+                        m = m.wrapping_shl(8) | b;
+                    }
+                    Self::splat(m)
+                };
+                // Sums the bits in pairs, then in nibbles, then in bytes. The
+                // masks clear the bits shifted in by signed lanes.
+                let x = self - ((self >> 1) & bytes(0x55));
+                let x = (x & bytes(0x33)) + ((x >> 2) & bytes(0x33));
+                (x + (x >> 4)) & bytes(0x0f)
+            }
+
             /// Returns the number of leading zeros in the binary
             /// representation of the lanes of `self`.
             #[inline]
@@ -30,6 +54,16 @@ macro_rules! impl_bit_manip {
             pub fn trailing_zeros(self) -> Self {
                 super::codegen::bit_manip::BitManip::cttz(self)
             }
+
+            /// Reverses the order of the bits in each lane of `self`.
+            ///
+            /// The least significant bit becomes the most significant bit,
+            /// the second least significant bit becomes the second most
+            /// significant bit, etc.
+            #[inline]
+            pub fn reverse_bits(self) -> Self {
+                super::codegen::bit_manip::BitManip::bitreverse(self)
+            }
         }
 
         test_if! {
@@ -93,6 +127,31 @@ macro_rules! impl_bit_manip {
                         test_func!(load_bytes(), count_zeros);
                     }
 
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn count_ones_per_byte() {
+                        let values = [
+                            $id::splat(0),
+                            $id::splat(!0),
+                            $id::splat(0b1000_0010),
+                            $id::splat(1 << (LANE_WIDTH - 1)),
+                            load_bytes(),
+                        ];
+                        for &x in values.iter() {
+                            let mut expected = x;
+                            for i in 0..$id::lanes() {
+                                let v = x.extract(i);
+                                let mut r: $elem_ty = 0;
+                                for k in 0..LANE_WIDTH / 8 {
+                                    let b = (v >> (8 * k)) & 0xff;
+                                    r |= (b.count_ones() as $elem_ty) << (8 * k);
+                                }
+                                expected = expected.replace(i, r);
+                            }
+                            assert_eq!(x.count_ones_per_byte(), expected);
+                        }
+                    }
+
                     #[cfg_attr(not(target_arch = "wasm32"), test)]
                     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
                     fn leading_zeros() {
@@ -122,8 +181,291 @@ macro_rules! impl_bit_manip {
                         );
                         test_func!(load_bytes(), trailing_zeros);
                     }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn reverse_bits() {
+                        test_func!($id::splat(0), reverse_bits);
+                        test_func!($id::splat(1), reverse_bits);
+                        test_func!($id::splat(0b1000_0010), reverse_bits);
+                        test_func!($id::splat(!0), reverse_bits);
+                        test_func!(
+                            $id::splat(1 << (LANE_WIDTH - 1)),
+                            reverse_bits
+                        );
+                        test_func!(load_bytes(), reverse_bits);
+                        let x = load_bytes();
+                        assert_eq!(x.reverse_bits().reverse_bits(), x);
+                    }
                 }
             }
         }
     };
 }
+
+macro_rules! impl_bit_manip_pow2 {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident
+     | $test_tt:tt) => {
+        impl $id {
+            /// Returns a mask whose lanes are `true` where the lane of
+            /// `self` is a power of two.
+            #[inline]
+            pub fn is_power_of_two(self) -> $mask_ty {
+                self.count_ones().eq($id::splat(1))
+            }
+
+            /// Returns the smallest power of two greater than or equal to
+            /// each lane of `self`.
+            ///
+            /// Lanes whose next power of two is greater than the maximum
+            /// value of the lane type are set to `0`.
+            #[inline]
+            pub fn next_power_of_two(self) -> Self {
+                const LANE_WIDTH: u32 =
+                    crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                // Set all the bits below the most significant one of
+                // `self - 1` and add one:
+                let mut x = self - $id::splat(1);
+                let mut s = 1;
+                while s < LANE_WIDTH {
+                    x |= x >> s;
+                    s *= 2;
+                }
+                let o = $id::splat(1);
+                self.le(o).select(o, x + o)
+            }
+        }
+
+        test_if! {
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _bit_manip_pow2>] {
+                    use super::*;
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn power_of_two() {
+                        const LANE_WIDTH: u32 = mem::size_of::<$elem_ty>() as u32 * 8;
+                        let max = $elem_ty::max_value();
+                        let values = [
+                            0, 1, 2, 3, 4, 5, 7, 8, 9, 63, 64, 65, 100,
+                            max, max - 1, max / 2, max / 2 + 1, max / 2 + 2,
+                            1 << (LANE_WIDTH - 2), (1 << (LANE_WIDTH - 2)) + 1,
+                        ];
+                        for j in 0..values.len() {
+                            let mut x = $id::splat(0);
+                            let mut p = $mask_ty::splat(false);
+                            let mut n = $id::splat(0);
+                            for i in 0..$id::lanes() {
+                                let v = values[(i + j) % values.len()];
+                                x = x.replace(i, v);
+                                p = p.replace(i, v.is_power_of_two());
+                                n = n.replace(
+                                    i, v.checked_next_power_of_two().unwrap_or(0)
+                                );
+                            }
+                            assert_eq!(x.is_power_of_two(), p);
+                            assert_eq!(x.next_power_of_two(), n);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_bit_manip_fields {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Gathers the bits of each lane of `self` selected by the
+            /// corresponding lane of `mask` into the contiguous low bits of
+            /// the result.
+            ///
+            /// This is the lane-wise equivalent of the BMI2 `pext`
+            /// instruction.
+            #[inline]
+            pub fn bit_extract(self, mask: Self) -> Self {
+                crate::codegen::bit_manip::BitPack::pext(self, mask)
+            }
+
+            /// Scatters the contiguous low bits of each lane of `self` to
+            /// the positions of the bits set in the corresponding lane of
+            /// `mask`.
+            ///
+            /// This is the lane-wise equivalent of the BMI2 `pdep`
+            /// instruction.
+            #[inline]
+            pub fn bit_deposit(self, mask: Self) -> Self {
+                crate::codegen::bit_manip::BitPack::pdep(self, mask)
+            }
+
+            /// Returns the `len` bits of each lane of `self` starting at
+            /// bit `start` in the low bits of the result.
+            ///
+            /// Bits past the end of the lane read as zero, like the BMI1
+            /// `bextr` instruction.
+            #[inline]
+            pub fn extract_bits(self, start: u32, len: u32) -> Self {
+                const LANE_WIDTH: u32 =
+                    crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                if start >= LANE_WIDTH {
+                    return Self::splat(0);
+                }
+                (self >> start) & Self::field_mask(len)
+            }
+
+            /// Replaces the `len` bits of each lane of `self` starting at
+            /// bit `start` with the low bits of the corresponding lane of
+            /// `value`.
+            ///
+            /// Bits of the field past the end of the lane are discarded.
+            #[inline]
+            pub fn insert_bits(self, value: Self, start: u32, len: u32) -> Self {
+                const LANE_WIDTH: u32 =
+                    crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                if start >= LANE_WIDTH {
+                    return self;
+                }
+                let m = Self::field_mask(len) << start;
+                (self & !m) | ((value << start) & m)
+            }
+
+            /// Returns a vector with the `len` low bits of each lane set.
+            #[inline]
+            fn field_mask(len: u32) -> Self {
+                const LANE_WIDTH: u32 =
+                    crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                if len >= LANE_WIDTH {
+                    Self::splat(!0)
+                } else {
+                    !(Self::splat(!0) << len)
+                }
+            }
+        }
+
+        test_if! {
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _bit_manip_fields>] {
+                    use super::*;
+
+                    const LANE_WIDTH: u32 = mem::size_of::<$elem_ty>() as u32 * 8;
+
+                    fn pext(x: $elem_ty, mut m: $elem_ty) -> $elem_ty {
+                        let mut r = 0;
+                        let mut k = 0;
+                        while m != 0 {
+                            let b = m.trailing_zeros();
+                            r |= ((x >> b) & 1) << k;
+                            m &= m - 1;
+                            k += 1;
+                        }
+                        r
+                    }
+
+                    fn pdep(x: $elem_ty, mut m: $elem_ty) -> $elem_ty {
+                        let mut r = 0;
+                        let mut k = 0;
+                        while m != 0 {
+                            let b = m.trailing_zeros();
+                            r |= ((x >> k) & 1) << b;
+                            m &= m - 1;
+                            k += 1;
+                        }
+                        r
+                    }
+
+                    const VALUES: [$elem_ty; 12] = [
+                        0, 1, 2, 0b1011_0110, 0x5555_5555, 0xaaaa_aaaa,
+                        0xf0f0_f0f0, 0x0123_4567, 0x8000_0001, 0xffff_0000,
+                        !0, !0 >> 1,
+                    ];
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn bit_extract_deposit() {
+                        let n = VALUES.len();
+                        for j in 0..n {
+                            for k in 0..n {
+                                let mut x = $id::splat(0);
+                                let mut m = $id::splat(0);
+                                let mut e = $id::splat(0);
+                                let mut d = $id::splat(0);
+                                for i in 0..$id::lanes() {
+                                    let xv = VALUES[(i + j) % n]
+                                        .rotate_left(i as u32);
+                                    let mv = VALUES[(i + k) % n];
+                                    x = x.replace(i, xv);
+                                    m = m.replace(i, mv);
+                                    e = e.replace(i, pext(xv, mv));
+                                    d = d.replace(i, pdep(xv, mv));
+                                }
+                                assert_eq!(x.bit_extract(m), e);
+                                assert_eq!(x.bit_deposit(m), d);
+                                assert_eq!(
+                                    x.bit_extract(m).bit_deposit(m),
+                                    x & m
+                                );
+                            }
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn extract_insert_bits() {
+                        let a = $id::splat(!0);
+                        let x = $id::splat(0xf0f0_f0f0 as $elem_ty);
+                        let z = $id::splat(0);
+                        for &(start, len) in &[
+                            (0, 0), (0, 1), (0, 8), (4, 4), (3, 7),
+                            (0, LANE_WIDTH), (1, LANE_WIDTH),
+                            (LANE_WIDTH - 1, 1), (LANE_WIDTH - 1, 8),
+                            (LANE_WIDTH, 1), (LANE_WIDTH + 1, LANE_WIDTH),
+                        ] {
+                            let mask = if len >= LANE_WIDTH {
+                                !0
+                            } else {
+                                !(!0 << len)
+                            };
+                            let shifted = if start >= LANE_WIDTH {
+                                0
+                            } else {
+                                (0xf0f0_f0f0 as $elem_ty) >> start
+                            };
+                            assert_eq!(
+                                x.extract_bits(start, len),
+                                $id::splat(shifted & mask)
+                            );
+
+                            let field = if start >= LANE_WIDTH {
+                                0
+                            } else {
+                                mask << start
+                            };
+                            assert_eq!(z.insert_bits(a, start, len),
+                                       $id::splat(field));
+                            assert_eq!(a.insert_bits(z, start, len),
+                                       $id::splat(!field));
+                            assert_eq!(
+                                x.insert_bits(
+                                    x.extract_bits(start, len), start, len
+                                ),
+                                x
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::*;
+
+impl_bit_manip_fields!([u32; 2]: u32x2 | test_v64);
+impl_bit_manip_fields!([u32; 4]: u32x4 | test_v128);
+impl_bit_manip_fields!([u32; 8]: u32x8 | test_v256);
+impl_bit_manip_fields!([u32; 16]: u32x16 | test_v512);
+impl_bit_manip_fields!([u64; 2]: u64x2 | test_v128);
+impl_bit_manip_fields!([u64; 4]: u64x4 | test_v256);
+impl_bit_manip_fields!([u64; 8]: u64x8 | test_v512);
@@ -86,6 +86,179 @@ impl<T> FromCast<T> for T {
     }
 }
 
+/// Rounding mode of the float-to-integer conversions of [`FromFloat`] and
+/// [`ToInt`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::missing_inline_in_public_items)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, ties to even.
+    NearestEven,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero, like `FromCast` and `as`.
+    Trunc,
+}
+
+/// Float-to-integer conversion from `T` to `Self` with an explicit rounding
+/// mode.
+///
+/// This is implemented for the integer vectors for which `FromCast<T>` is
+/// implemented from a floating-point vector `T`. Each lane of `T` is first
+/// rounded to an integer with the given [`RoundingMode`], and then:
+///
+/// * `from_float` **saturates**, like `as` on the scalar types: NaN lanes
+///   are converted to `0`, and the lanes that are out of the range of the
+///   integer type to its `MIN` or `MAX` value,
+///
+/// * `from_float_unchecked` converts the lanes without any check, which is
+///   faster on most targets.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_simd_2::*;
+/// let x = f32x4::new(2.5, -1.5, 1e10, std::f32::NAN);
+/// let r = i32x4::from_float(x, RoundingMode::NearestEven);
+/// assert_eq!(r, i32x4::new(2, -2, i32::max_value(), 0));
+/// let r: u8x4 = x.to_int(RoundingMode::Floor);
+/// assert_eq!(r, u8x4::new(2, 0, 255, 0));
+/// ```
+pub trait FromFloat<T>: crate::marker::Sized {
+    /// Rounds each lane of `x` with `mode` and converts it, saturating.
+    fn from_float(x: T, mode: RoundingMode) -> Self;
+
+    /// Rounds each lane of `x` with `mode` and converts it, without
+    /// checking that the result is in range.
+    ///
+    /// # Safety
+    ///
+    /// No lane of `x` may be NaN, and all rounded lanes of `x` must be
+    /// representable in the lanes of `Self`.
+    unsafe fn from_float_unchecked(x: T, mode: RoundingMode) -> Self;
+}
+
+/// Float-to-integer conversion from `Self` to `T` with an explicit rounding
+/// mode.
+///
+/// See [`FromFloat`], which implies `ToInt`.
+pub trait ToInt<T>: crate::marker::Sized {
+    /// Rounds each lane of `self` with `mode` and converts it, saturating.
+    fn to_int(self, mode: RoundingMode) -> T;
+
+    /// Rounds each lane of `self` with `mode` and converts it, without
+    /// checking that the result is in range.
+    ///
+    /// # Safety
+    ///
+    /// See [`FromFloat::from_float_unchecked`].
+    unsafe fn to_int_unchecked(self, mode: RoundingMode) -> T;
+}
+
+/// `FromFloat` implies `ToInt`.
+impl<T, U> ToInt<U> for T
+where
+    U: FromFloat<T>,
+{
+    #[inline]
+    fn to_int(self, mode: RoundingMode) -> U {
+        U::from_float(self, mode)
+    }
+
+    #[inline]
+    unsafe fn to_int_unchecked(self, mode: RoundingMode) -> U {
+        U::from_float_unchecked(self, mode)
+    }
+}
+
+/// Saturating numeric cast from `T` to `Self`.
+///
+/// This is implemented for the integer vectors for which `FromCast<T>` is
+/// implemented from an integer vector `T`. Unlike `FromCast`, which
+/// truncates the lanes or reinterprets their sign, the lanes that are out of
+/// the range of the lanes of `Self` are clamped to its `MIN` or `MAX` value.
+/// For example, negative lanes are converted to `0` when casting from a
+/// signed to an unsigned vector.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_simd_2::*;
+/// let x = i32x4::new(-70000, -1, 255, 70000);
+/// assert_eq!(i16x4::from_saturating_cast(x), i16x4::new(-32768, -1, 255, 32767));
+/// let r: u8x4 = x.saturating_cast();
+/// assert_eq!(r, u8x4::new(0, 0, 255, 255));
+/// ```
+pub trait FromSaturatingCast<T>: crate::marker::Sized {
+    /// Saturating numeric cast from `T` to `Self`.
+    fn from_saturating_cast(x: T) -> Self;
+}
+
+/// Saturating numeric cast from `Self` to `T`.
+///
+/// See [`FromSaturatingCast`], which implies `SaturatingCast`.
+pub trait SaturatingCast<T>: crate::marker::Sized {
+    /// Saturating numeric cast from `self` to `T`.
+    fn saturating_cast(self) -> T;
+}
+
+/// `FromSaturatingCast` implies `SaturatingCast`.
+impl<T, U> SaturatingCast<U> for T
+where
+    U: FromSaturatingCast<T>,
+{
+    #[inline]
+    fn saturating_cast(self) -> U {
+        U::from_saturating_cast(self)
+    }
+}
+
+/// Saturating narrowing of two vectors of type `T` into `Self`, which has
+/// twice as many lanes of half the width.
+///
+/// The lanes of `a` are [saturated](trait.FromSaturatingCast.html) into the
+/// first half of the lanes of the result, and the lanes of `b` into the
+/// second half, like the `pack` instructions of many architectures, e.g.,
+/// `packssdw` and `packuswb` on x86 or `sqxtn` and `sqxtun` on AArch64.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_simd_2::*;
+/// let a = i32x4::new(-70000, -1, 255, 70000);
+/// let b = i32x4::new(0, 1, 2, 3);
+/// assert_eq!(
+///     i16x8::from_pack(a, b),
+///     i16x8::new(-32768, -1, 255, 32767, 0, 1, 2, 3)
+/// );
+/// let r: u16x8 = a.pack(b);
+/// assert_eq!(r, u16x8::new(0, 0, 255, 65535, 0, 1, 2, 3));
+/// ```
+pub trait FromPack<T>: crate::marker::Sized {
+    /// Saturating narrowing of `a` and `b` into `Self`.
+    fn from_pack(a: T, b: T) -> Self;
+}
+
+/// Saturating narrowing of `self` and another vector into `T`.
+///
+/// See [`FromPack`], which implies `Pack`.
+pub trait Pack<T>: crate::marker::Sized {
+    /// Saturating narrowing of `self` and `b` into `T`.
+    fn pack(self, b: Self) -> T;
+}
+
+/// `FromPack` implies `Pack`.
+impl<T, U> Pack<U> for T
+where
+    U: FromPack<T>,
+{
+    #[inline]
+    fn pack(self, b: Self) -> U {
+        U::from_pack(self, b)
+    }
+}
+
 #[macro_use]
 mod macros;
 
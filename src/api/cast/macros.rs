@@ -80,3 +80,307 @@ macro_rules! impl_into_cast {
         )*
     }
 }
+
+macro_rules! round_float {
+    ($x:expr, $mode:expr) => {
+        match $mode {
+            crate::api::cast::RoundingMode::NearestEven => $x.round_ties_even(),
+            crate::api::cast::RoundingMode::Floor => $x.floor(),
+            crate::api::cast::RoundingMode::Ceil => $x.ceil(),
+            crate::api::cast::RoundingMode::Trunc => $x.trunc(),
+        }
+    };
+}
+
+macro_rules! impl_from_float_ {
+    ($id:ident[$test_tt:tt]: $from_ty:ident) => {
+        impl crate::api::cast::FromFloat<$from_ty> for $id {
+            #[inline]
+            fn from_float(x: $from_ty, mode: crate::api::cast::RoundingMode) -> Self {
+                type I = <$id as crate::sealed::Simd>::Element;
+                type F = <$from_ty as crate::sealed::Simd>::Element;
+                // The integers are in `[lo, hi)`, where both bounds are
+                // powers of two or zero, and are exact as floats:
+                let lo = $from_ty::splat(I::min_value() as F);
+                let hi = $from_ty::splat(((I::max_value() >> 1) + 1) as F * 2.);
+
+                let r = round_float!(x, mode);
+                let below = r.lt(lo);
+                let above = r.ge(hi);
+                // Only the lanes that are in range are converted, since
+                // `FromCast` is undefined for the others:
+                let in_range = !(below | above | r.is_nan());
+                let v: $id = in_range.select(r, $from_ty::splat(0.)).cast();
+                let v = below.select($id::splat(I::min_value()), v);
+                above.select($id::splat(I::max_value()), v)
+            }
+
+            #[inline]
+            unsafe fn from_float_unchecked(
+                x: $from_ty, mode: crate::api::cast::RoundingMode,
+            ) -> Self {
+                round_float!(x, mode).cast()
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _from_float_ $from_ty>] {
+                    use super::*;
+                    use crate::api::cast::RoundingMode::*;
+
+                    type I = <$id as crate::sealed::Simd>::Element;
+                    type F = <$from_ty as crate::sealed::Simd>::Element;
+
+                    fn values() -> [F; 24] {
+                        [
+                            0., -0., 0.5, -0.5, 1.5, -1.5, 2.5, -2.5, 1.2, -1.7,
+                            100.7, -100.7,
+                            I::max_value() as F, I::min_value() as F,
+                            I::max_value() as F * 2., I::min_value() as F * 2. - 1.,
+                            // Largest float less than `MAX + 1`:
+                            ((I::max_value() >> 1) + 1) as F * 2. * (1. - F::EPSILON / 2.),
+                            1e10, -1e10, 1e30, -1e30,
+                            F::INFINITY, F::NEG_INFINITY, F::NAN,
+                        ]
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn from_float() {
+                        let modes = [NearestEven, Floor, Ceil, Trunc];
+                        for &mode in modes.iter() {
+                            for &v in values().iter() {
+                                let x = $from_ty::splat(v);
+                                let r = $id::from_float(x, mode);
+                                assert_eq!(r, x.to_int(mode));
+
+                                let rounded = match mode {
+                                    NearestEven => x.round_ties_even(),
+                                    Floor => x.floor(),
+                                    Ceil => x.ceil(),
+                                    Trunc => x.trunc(),
+                                }.extract(0);
+                                // Scalar `as` saturates, and maps NaN to zero:
+                                let e = rounded as I;
+                                assert_eq!(r, $id::splat(e), "{:?}({:e})", mode, v);
+
+                                let exact = rounded.is_finite()
+                                    && rounded >= I::min_value() as F
+                                    && rounded < ((I::max_value() >> 1) + 1) as F * 2.;
+                                if exact {
+                                    let u: $id = unsafe { x.to_int_unchecked(mode) };
+                                    assert_eq!(u, r, "{:?}({:e})", mode, v);
+                                }
+                            }
+                        }
+
+                        // Each lane is rounded independently:
+                        let mut x = $from_ty::splat(0.5);
+                        let mut e = $id::splat(0);
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                x = x.replace(i, 1.5);
+                                e = e.replace(i, 2);
+                            }
+                        }
+                        assert_eq!($id::from_float(x, NearestEven), e);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_float {
+    ($id:ident[$test_tt:tt]: $($from_ty:ident),*) => {
+        $(
+            impl_from_float_!($id[$test_tt]: $from_ty);
+        )*
+    }
+}
+
+macro_rules! impl_from_saturating_cast_ {
+    ($id:ident[$test_tt:tt]: $from_ty:ident) => {
+        impl crate::api::cast::FromSaturatingCast<$from_ty> for $id {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            fn from_saturating_cast(x: $from_ty) -> Self {
+                type S = <$from_ty as crate::sealed::Simd>::Element;
+                type D = <$id as crate::sealed::Simd>::Element;
+                // The range of `D` intersected with the range of `S`: the
+                // minimum values fit in an `i128`, the maximum values in an
+                // `u128`.
+                let lo = crate::cmp::max(S::min_value() as i128, D::min_value() as i128);
+                let hi = crate::cmp::min(S::max_value() as u128, D::max_value() as u128);
+                x.max($from_ty::splat(lo as S)).min($from_ty::splat(hi as S)).cast()
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _from_saturating_cast_ $from_ty>] {
+                    use super::*;
+                    use core::convert::TryFrom;
+
+                    type S = <$from_ty as crate::sealed::Simd>::Element;
+                    type D = <$id as crate::sealed::Simd>::Element;
+
+                    fn saturate(x: S) -> D {
+                        let negative = S::min_value() != 0 && x.leading_zeros() == 0;
+                        D::try_from(x).unwrap_or(
+                            if negative { D::min_value() } else { D::max_value() }
+                        )
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn from_saturating_cast() {
+                        let values = [
+                            0, 1, 7, S::min_value(), S::max_value(),
+                            S::min_value() / 2, S::max_value() / 2,
+                            D::min_value() as S, D::max_value() as S,
+                            (D::min_value() as S).wrapping_sub(1),
+                            (D::max_value() as S).wrapping_add(1),
+                            (0 as S).wrapping_sub(1),
+                        ];
+                        for &v in values.iter() {
+                            let r = $id::from_saturating_cast($from_ty::splat(v));
+                            assert_eq!(r, $id::splat(saturate(v)), "{}", v);
+                            let r2: $id = $from_ty::splat(v).saturating_cast();
+                            assert_eq!(r, r2);
+                        }
+
+                        let mut x = $from_ty::splat(0);
+                        let mut e = $id::splat(0);
+                        for i in 0..$id::lanes() {
+                            let v = values[i % values.len()];
+                            x = x.replace(i, v);
+                            e = e.replace(i, saturate(v));
+                        }
+                        assert_eq!($id::from_saturating_cast(x), e);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_saturating_cast {
+    ($id:ident[$test_tt:tt]: $($from_ty:ident),*) => {
+        $(
+            impl_from_saturating_cast_!($id[$test_tt]: $from_ty);
+        )*
+    }
+}
+
+macro_rules! impl_from_pack_ {
+    ($id:ident[$test_tt:tt]: $from_ty:ident => $half_ty:ident) => {
+        impl crate::api::cast::FromPack<$from_ty> for $id {
+            #[inline]
+            fn from_pack(a: $from_ty, b: $from_ty) -> Self {
+                let a = $half_ty::from_saturating_cast(a);
+                let b = $half_ty::from_saturating_cast(b);
+                $id::concat(a, b)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _from_pack_ $from_ty>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn from_pack() {
+                        type S = <$from_ty as crate::sealed::Simd>::Element;
+                        let mut a = $from_ty::splat(0);
+                        let mut b = $from_ty::splat(0);
+                        for i in 0..$from_ty::lanes() {
+                            a = a.replace(i, S::max_value().wrapping_sub(i as S));
+                            b = b.replace(i, S::min_value().wrapping_add(i as S));
+                        }
+                        let r = $id::from_pack(a, b);
+                        let ra: $half_ty = a.saturating_cast();
+                        let rb: $half_ty = b.saturating_cast();
+                        for i in 0..$from_ty::lanes() {
+                            assert_eq!(r.extract(i), ra.extract(i));
+                            assert_eq!(r.extract(i + $from_ty::lanes()), rb.extract(i));
+                        }
+                        let r2: $id = a.pack(b);
+                        assert_eq!(r, r2);
+                    }
+                }
+            }
+        }
+    };
+    // The halves of vectors of two lanes are their lanes (see `split`), so
+    // each input vector of one lane is saturated to a lane.
+    ($id:ident[$test_tt:tt]: $from_ty:ident => | $lane:ident) => {
+        impl crate::api::cast::FromPack<$from_ty> for $id {
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            fn from_pack(a: $from_ty, b: $from_ty) -> Self {
+                type S = <$from_ty as crate::sealed::Simd>::Element;
+                let lo = crate::cmp::max(S::min_value() as i128, $lane::min_value() as i128);
+                let hi = crate::cmp::min(S::max_value() as u128, $lane::max_value() as u128);
+                let saturate = |x: $from_ty| {
+                    x.max($from_ty::splat(lo as S))
+                        .min($from_ty::splat(hi as S))
+                        .extract(0) as $lane
+                };
+                $id::concat(saturate(a), saturate(b))
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _from_pack_ $from_ty>] {
+                    use super::*;
+                    use core::convert::TryFrom;
+
+                    type S = <$from_ty as crate::sealed::Simd>::Element;
+
+                    fn saturate(x: S) -> $lane {
+                        let negative = S::min_value() != 0 && x.leading_zeros() == 0;
+                        $lane::try_from(x).unwrap_or(
+                            if negative { $lane::min_value() } else { $lane::max_value() }
+                        )
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn from_pack() {
+                        let values = [
+                            0, 1, S::min_value(), S::max_value(),
+                            $lane::min_value() as S, $lane::max_value() as S,
+                            ($lane::min_value() as S).wrapping_sub(1),
+                            ($lane::max_value() as S).wrapping_add(1),
+                        ];
+                        for &x in values.iter() {
+                            for &y in values.iter() {
+                                let (a, b) = ($from_ty::splat(x), $from_ty::splat(y));
+                                let r = $id::from_pack(a, b);
+                                assert_eq!(r, $id::new(saturate(x), saturate(y)));
+                                let r2: $id = a.pack(b);
+                                assert_eq!(r, r2);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_pack {
+    ($id:ident[$test_tt:tt]: $($from_ty:ident => $half_ty:ident),*) => {
+        $(
+            impl_from_pack_!($id[$test_tt]: $from_ty => $half_ty);
+        )*
+    };
+    ($id:ident[$test_tt:tt]: $($from_ty:ident => | $lane:ident),*) => {
+        $(
+            impl_from_pack_!($id[$test_tt]: $from_ty => | $lane);
+        )*
+    };
+}
@@ -300,3 +300,36 @@ impl_from_cast_mask!(
 impl_from_cast!(i128x1[test_v128]: u128x1, m128x1);
 impl_from_cast!(u128x1[test_v128]: i128x1, m128x1);
 impl_from_cast!(m128x1[test_v128]: i128x1, u128x1);
+
+impl_from_float!(i8x16[test_v128]: f32x16);
+impl_from_float!(u8x16[test_v128]: f32x16);
+impl_from_float!(i16x8[test_v128]: f32x8, f64x8);
+impl_from_float!(u16x8[test_v128]: f32x8, f64x8);
+impl_from_float!(i32x4[test_v128]: f32x4, f64x4);
+impl_from_float!(u32x4[test_v128]: f32x4, f64x4);
+impl_from_float!(i64x2[test_v128]: f32x2, f64x2);
+impl_from_float!(u64x2[test_v128]: f32x2, f64x2);
+impl_from_float!(isizex2[test_v128]: f32x2, f64x2);
+impl_from_float!(usizex2[test_v128]: f32x2, f64x2);
+
+impl_from_saturating_cast!(i8x16[test_v128]: u8x16, i16x16, u16x16, i32x16, u32x16);
+impl_from_saturating_cast!(u8x16[test_v128]: i8x16, i16x16, u16x16, i32x16, u32x16);
+impl_from_saturating_cast!(i16x8[test_v128]: i8x8, u8x8, u16x8, i32x8, u32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(u16x8[test_v128]: i8x8, u8x8, i16x8, i32x8, u32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(i32x4[test_v128]: i8x4, u8x4, i16x4, u16x4, u32x4, i64x4, u64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(u32x4[test_v128]: i8x4, u8x4, i16x4, u16x4, i32x4, i64x4, u64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(i64x2[test_v128]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, u64x2, i128x2, u128x2, isizex2, usizex2);
+impl_from_saturating_cast!(u64x2[test_v128]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, i128x2, u128x2, isizex2, usizex2);
+impl_from_saturating_cast!(isizex2[test_v128]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, i128x2, u128x2, usizex2);
+impl_from_saturating_cast!(usizex2[test_v128]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, i128x2, u128x2, isizex2);
+impl_from_saturating_cast!(i128x1[test_v128]: u128x1);
+impl_from_saturating_cast!(u128x1[test_v128]: i128x1);
+
+impl_from_pack!(i8x16[test_v128]: i16x8 => i8x8, u16x8 => i8x8);
+impl_from_pack!(u8x16[test_v128]: i16x8 => u8x8, u16x8 => u8x8);
+impl_from_pack!(i16x8[test_v128]: i32x4 => i16x4, u32x4 => i16x4);
+impl_from_pack!(u16x8[test_v128]: i32x4 => u16x4, u32x4 => u16x4);
+impl_from_pack!(i32x4[test_v128]: i64x2 => i32x2, u64x2 => i32x2);
+impl_from_pack!(u32x4[test_v128]: i64x2 => u32x2, u64x2 => u32x2);
+impl_from_pack!(i64x2[test_v128]: i128x1 => | i64, u128x1 => | i64);
+impl_from_pack!(u64x2[test_v128]: i128x1 => | u64, u128x1 => | u64);
@@ -66,3 +66,9 @@ impl_from_cast_mask!(
     usizex2,
     msizex2
 );
+
+impl_from_float!(i8x2[test_v16]: f32x2, f64x2);
+impl_from_float!(u8x2[test_v16]: f32x2, f64x2);
+
+impl_from_saturating_cast!(i8x2[test_v16]: u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, i128x2, u128x2, isizex2, usizex2);
+impl_from_saturating_cast!(u8x2[test_v16]: i8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, i128x2, u128x2, isizex2, usizex2);
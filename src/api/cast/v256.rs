@@ -296,3 +296,36 @@ impl_from_cast_mask!(
     isizex4,
     usizex4
 );
+
+impl_from_float!(i16x16[test_v256]: f32x16);
+impl_from_float!(u16x16[test_v256]: f32x16);
+impl_from_float!(i32x8[test_v256]: f32x8, f64x8);
+impl_from_float!(u32x8[test_v256]: f32x8, f64x8);
+impl_from_float!(i64x4[test_v256]: f32x4, f64x4);
+impl_from_float!(u64x4[test_v256]: f32x4, f64x4);
+impl_from_float!(i128x2[test_v256]: f32x2, f64x2);
+impl_from_float!(u128x2[test_v256]: f32x2, f64x2);
+impl_from_float!(isizex4[test_v256]: f32x4, f64x4);
+impl_from_float!(usizex4[test_v256]: f32x4, f64x4);
+
+impl_from_saturating_cast!(i8x32[test_v256]: u8x32, i16x32, u16x32);
+impl_from_saturating_cast!(u8x32[test_v256]: i8x32, i16x32, u16x32);
+impl_from_saturating_cast!(i16x16[test_v256]: i8x16, u8x16, u16x16, i32x16, u32x16);
+impl_from_saturating_cast!(u16x16[test_v256]: i8x16, u8x16, i16x16, i32x16, u32x16);
+impl_from_saturating_cast!(i32x8[test_v256]: i8x8, u8x8, i16x8, u16x8, u32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(u32x8[test_v256]: i8x8, u8x8, i16x8, u16x8, i32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(i64x4[test_v256]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, u64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(u64x4[test_v256]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, i64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(i128x2[test_v256]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, u128x2, isizex2, usizex2);
+impl_from_saturating_cast!(u128x2[test_v256]: i8x2, u8x2, i16x2, u16x2, i32x2, u32x2, i64x2, u64x2, i128x2, isizex2, usizex2);
+impl_from_saturating_cast!(isizex4[test_v256]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, i64x4, u64x4, i128x4, u128x4, usizex4);
+impl_from_saturating_cast!(usizex4[test_v256]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, i64x4, u64x4, i128x4, u128x4, isizex4);
+
+impl_from_pack!(i8x32[test_v256]: i16x16 => i8x16, u16x16 => i8x16);
+impl_from_pack!(u8x32[test_v256]: i16x16 => u8x16, u16x16 => u8x16);
+impl_from_pack!(i16x16[test_v256]: i32x8 => i16x8, u32x8 => i16x8);
+impl_from_pack!(u16x16[test_v256]: i32x8 => u16x8, u32x8 => u16x8);
+impl_from_pack!(i32x8[test_v256]: i64x4 => i32x4, u64x4 => i32x4);
+impl_from_pack!(u32x8[test_v256]: i64x4 => u32x4, u64x4 => u32x4);
+impl_from_pack!(i64x4[test_v256]: i128x2 => i64x2, u128x2 => i64x2);
+impl_from_pack!(u64x4[test_v256]: i128x2 => u64x2, u128x2 => u64x2);
@@ -207,3 +207,34 @@ impl_from_cast_mask!(
     isizex8,
     usizex8
 );
+
+impl_from_float!(i32x16[test_v512]: f32x16);
+impl_from_float!(u32x16[test_v512]: f32x16);
+impl_from_float!(i64x8[test_v512]: f32x8, f64x8);
+impl_from_float!(u64x8[test_v512]: f32x8, f64x8);
+impl_from_float!(i128x4[test_v512]: f32x4, f64x4);
+impl_from_float!(u128x4[test_v512]: f32x4, f64x4);
+impl_from_float!(isizex8[test_v512]: f32x8, f64x8);
+impl_from_float!(usizex8[test_v512]: f32x8, f64x8);
+
+impl_from_saturating_cast!(i8x64[test_v512]: u8x64);
+impl_from_saturating_cast!(u8x64[test_v512]: i8x64);
+impl_from_saturating_cast!(i16x32[test_v512]: i8x32, u8x32, u16x32);
+impl_from_saturating_cast!(u16x32[test_v512]: i8x32, u8x32, i16x32);
+impl_from_saturating_cast!(i32x16[test_v512]: i8x16, u8x16, i16x16, u16x16, u32x16);
+impl_from_saturating_cast!(u32x16[test_v512]: i8x16, u8x16, i16x16, u16x16, i32x16);
+impl_from_saturating_cast!(i64x8[test_v512]: i8x8, u8x8, i16x8, u16x8, i32x8, u32x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(u64x8[test_v512]: i8x8, u8x8, i16x8, u16x8, i32x8, u32x8, i64x8, isizex8, usizex8);
+impl_from_saturating_cast!(i128x4[test_v512]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, i64x4, u64x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(u128x4[test_v512]: i8x4, u8x4, i16x4, u16x4, i32x4, u32x4, i64x4, u64x4, i128x4, isizex4, usizex4);
+impl_from_saturating_cast!(isizex8[test_v512]: i8x8, u8x8, i16x8, u16x8, i32x8, u32x8, i64x8, u64x8, usizex8);
+impl_from_saturating_cast!(usizex8[test_v512]: i8x8, u8x8, i16x8, u16x8, i32x8, u32x8, i64x8, u64x8, isizex8);
+
+impl_from_pack!(i8x64[test_v512]: i16x32 => i8x32, u16x32 => i8x32);
+impl_from_pack!(u8x64[test_v512]: i16x32 => u8x32, u16x32 => u8x32);
+impl_from_pack!(i16x32[test_v512]: i32x16 => i16x16, u32x16 => i16x16);
+impl_from_pack!(u16x32[test_v512]: i32x16 => u16x16, u32x16 => u16x16);
+impl_from_pack!(i32x16[test_v512]: i64x8 => i32x8, u64x8 => i32x8);
+impl_from_pack!(u32x16[test_v512]: i64x8 => u32x8, u64x8 => u32x8);
+impl_from_pack!(i64x8[test_v512]: i128x4 => i64x4, u128x4 => i64x4);
+impl_from_pack!(u64x8[test_v512]: i128x4 => u64x4, u128x4 => u64x4);
@@ -206,3 +206,22 @@ impl_from_cast_mask!(
     usizex2,
     msizex2
 );
+
+impl_from_float!(i8x8[test_v64]: f32x8, f64x8);
+impl_from_float!(u8x8[test_v64]: f32x8, f64x8);
+impl_from_float!(i16x4[test_v64]: f32x4, f64x4);
+impl_from_float!(u16x4[test_v64]: f32x4, f64x4);
+impl_from_float!(i32x2[test_v64]: f32x2, f64x2);
+impl_from_float!(u32x2[test_v64]: f32x2, f64x2);
+
+impl_from_saturating_cast!(i8x8[test_v64]: u8x8, i16x8, u16x8, i32x8, u32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(u8x8[test_v64]: i8x8, i16x8, u16x8, i32x8, u32x8, i64x8, u64x8, isizex8, usizex8);
+impl_from_saturating_cast!(i16x4[test_v64]: i8x4, u8x4, u16x4, i32x4, u32x4, i64x4, u64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(u16x4[test_v64]: i8x4, u8x4, i16x4, i32x4, u32x4, i64x4, u64x4, i128x4, u128x4, isizex4, usizex4);
+impl_from_saturating_cast!(i32x2[test_v64]: i8x2, u8x2, i16x2, u16x2, u32x2, i64x2, u64x2, i128x2, u128x2, isizex2, usizex2);
+impl_from_saturating_cast!(u32x2[test_v64]: i8x2, u8x2, i16x2, u16x2, i32x2, i64x2, u64x2, i128x2, u128x2, isizex2, usizex2);
+
+impl_from_pack!(i8x8[test_v64]: i16x4 => i8x4, u16x4 => i8x4);
+impl_from_pack!(u8x8[test_v64]: i16x4 => u8x4, u16x4 => u8x4);
+impl_from_pack!(i16x4[test_v64]: i32x2 => i16x2, u32x2 => i16x2);
+impl_from_pack!(u16x4[test_v64]: i32x2 => u16x2, u32x2 => u16x2);
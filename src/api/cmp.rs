@@ -14,3 +14,6 @@ mod ord;
 
 #[macro_use]
 mod vertical;
+
+#[macro_use]
+mod total_ord;
@@ -1,4 +1,4 @@
-//! Implements `Eq` for vector types.
+//! Implements `Eq` for vector types and their lexicographical order.
 
 macro_rules! impl_cmp_eq {
     (
@@ -7,6 +7,20 @@ macro_rules! impl_cmp_eq {
         ($true:expr, $false:expr)
     ) => {
         impl crate::cmp::Eq for $id {}
+
+        // FIXME: https://github.com/rust-lang-nursery/rust-clippy/issues/2892
+        #[allow(clippy::partialeq_ne_impl)]
+        impl crate::cmp::PartialEq<LexicographicallyOrdered<$id>> for LexicographicallyOrdered<$id> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+            #[inline]
+            fn ne(&self, other: &Self) -> bool {
+                self.0 != other.0
+            }
+        }
+
         impl crate::cmp::Eq for LexicographicallyOrdered<$id> {}
 
         test_if!{
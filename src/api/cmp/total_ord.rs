@@ -0,0 +1,201 @@
+//! Implements the IEEE 754 total order of floating-point vectors: vertical
+//! comparisons, and `Ord` for their lexicographical order.
+
+macro_rules! impl_cmp_float_total_ord {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident, $uid:ident
+     | $test_tt:tt) => {
+        impl $id {
+            /// Maps each lane to an unsigned integer whose order is the IEEE
+            /// 754 total order of the lane.
+            ///
+            /// That is, `a.to_ordered_bits() < b.to_ordered_bits()` if and
+            /// only if `a` is less than `b` in the order: `-NaN < -∞ < ... <
+            /// -0.0 < +0.0 < ... < +∞ < +NaN`, where NaNs are further ordered
+            /// by their payload. This makes the integer lanes suitable as
+            /// sort keys, e.g., for radix sorts.
+            #[inline]
+            pub fn to_ordered_bits(self) -> $uid {
+                let s = Self::splat(-0.).to_bits();
+                let bits = self.to_bits();
+                // Negative numbers are ordered in reverse, and before the
+                // positive numbers:
+                self.is_sign_negative().select(!bits, bits | s)
+            }
+
+            /// Lane-wise equality in the IEEE 754 total order.
+            ///
+            /// Unlike [`eq`](#method.eq), NaNs are equal to the NaNs with the
+            /// same bit representation, and `-0.0` is not equal to `+0.0`.
+            #[inline]
+            pub fn total_eq(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().eq(other.to_ordered_bits())
+            }
+
+            /// Lane-wise inequality in the IEEE 754 total order.
+            #[inline]
+            pub fn total_ne(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().ne(other.to_ordered_bits())
+            }
+
+            /// Lane-wise less-than in the IEEE 754 total order.
+            #[inline]
+            pub fn total_lt(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().lt(other.to_ordered_bits())
+            }
+
+            /// Lane-wise less-than-or-equals in the IEEE 754 total order.
+            #[inline]
+            pub fn total_le(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().le(other.to_ordered_bits())
+            }
+
+            /// Lane-wise greater-than in the IEEE 754 total order.
+            #[inline]
+            pub fn total_gt(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().gt(other.to_ordered_bits())
+            }
+
+            /// Lane-wise greater-than-or-equals in the IEEE 754 total order.
+            #[inline]
+            pub fn total_ge(self, other: Self) -> $mask_ty {
+                self.to_ordered_bits().ge(other.to_ordered_bits())
+            }
+
+            /// Returns a wrapper that implements `PartialOrd`.
+            ///
+            /// The lanes are compared in the IEEE 754 total order, so this is
+            /// the same as [`lex_ord`](#method.lex_ord).
+            #[inline]
+            pub fn partial_lex_ord(&self) -> LexicographicallyOrdered<$id> {
+                LexicographicallyOrdered(*self)
+            }
+
+            /// Returns a wrapper that implements `Ord`.
+            ///
+            /// The lanes are compared in the IEEE 754 total order, see
+            /// [`to_ordered_bits`](#method.to_ordered_bits).
+            #[inline]
+            pub fn lex_ord(&self) -> LexicographicallyOrdered<$id> {
+                LexicographicallyOrdered(*self)
+            }
+        }
+
+        impl crate::cmp::PartialEq<LexicographicallyOrdered<$id>> for LexicographicallyOrdered<$id> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.total_eq(other.0).all()
+            }
+        }
+
+        impl crate::cmp::Eq for LexicographicallyOrdered<$id> {}
+
+        impl crate::cmp::PartialOrd<LexicographicallyOrdered<$id>> for LexicographicallyOrdered<$id> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<crate::cmp::Ordering> {
+                Some(crate::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl crate::cmp::Ord for LexicographicallyOrdered<$id> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> crate::cmp::Ordering {
+                let a = self.0.to_ordered_bits();
+                let b = other.0.to_ordered_bits();
+                a.lex_ord().cmp(&b.lex_ord())
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _cmp_total_ord>] {
+                    use super::*;
+                    use crate::cmp::Ordering;
+
+                    // Increasing in the total order:
+                    fn values() -> [$elem_ty; 16] {
+                        let tiny = $elem_ty::from_bits(1);
+                        [
+                            -crate::$elem_ty::NAN,
+                            crate::$elem_ty::NEG_INFINITY,
+                            crate::$elem_ty::MIN,
+                            -1.,
+                            -crate::$elem_ty::MIN_POSITIVE,
+                            -tiny,
+                            -0.,
+                            0.,
+                            tiny,
+                            crate::$elem_ty::MIN_POSITIVE,
+                            1.,
+                            1. + crate::$elem_ty::EPSILON,
+                            crate::$elem_ty::MAX,
+                            crate::$elem_ty::INFINITY,
+                            crate::$elem_ty::NAN,
+                            $elem_ty::from_bits(crate::$elem_ty::NAN.to_bits() + 1),
+                        ]
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn total_cmp() {
+                        let t = $mask_ty::splat(true);
+                        let f = $mask_ty::splat(false);
+                        let m = |b: bool| if b { t } else { f };
+                        for (i, &a) in values().iter().enumerate() {
+                            for (j, &b) in values().iter().enumerate() {
+                                let (x, y) = ($id::splat(a), $id::splat(b));
+                                assert_eq!(x.total_eq(y), m(i == j));
+                                assert_eq!(x.total_ne(y), m(i != j));
+                                assert_eq!(x.total_lt(y), m(i < j));
+                                assert_eq!(x.total_le(y), m(i <= j));
+                                assert_eq!(x.total_gt(y), m(i > j));
+                                assert_eq!(x.total_ge(y), m(i >= j));
+                                assert_eq!(
+                                    x.lex_ord().cmp(&y.lex_ord()),
+                                    i.cmp(&j)
+                                );
+                                assert_eq!(
+                                    x.partial_lex_ord().partial_cmp(&y.partial_lex_ord()),
+                                    Some(i.cmp(&j))
+                                );
+                                assert_eq!(x.lex_ord() == y.lex_ord(), i == j);
+                            }
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn to_ordered_bits() {
+                        let v = values();
+                        for w in v.windows(2) {
+                            let a = $id::splat(w[0]).to_ordered_bits();
+                            let b = $id::splat(w[1]).to_ordered_bits();
+                            assert!(a.lt(b).all(), "{:e} < {:e}", w[0], w[1]);
+                        }
+                        // The zeros are adjacent, in the middle of the range:
+                        assert_eq!($id::splat(-0.).to_ordered_bits(), !$uid::splat(1) >> 1);
+                        assert_eq!($id::splat(0.).to_ordered_bits(), !(!$uid::splat(0) >> 1));
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn lex_ord() {
+                        fn foo<E: crate::cmp::Ord>(_: E) {}
+                        foo($id::splat(0.).lex_ord());
+
+                        // The first lane that differs decides:
+                        let a = $id::splat(1.);
+                        let b = a.replace($id::lanes() - 1, crate::$elem_ty::NAN);
+                        assert_eq!(a.lex_ord().cmp(&b.lex_ord()), Ordering::Less);
+                        let c = b.replace(0, -0.);
+                        assert_eq!(c.lex_ord().cmp(&b.lex_ord()), Ordering::Less);
+                        assert_eq!(b.lex_ord().cmp(&b.lex_ord()), Ordering::Equal);
+                        if $id::lanes() > 1 {
+                            assert_eq!(c.lex_ord().cmp(&a.lex_ord()), Ordering::Less);
+                            let d = $id::splat(-0.).replace(0, 0.);
+                            assert_eq!(d.lex_ord().cmp(&$id::splat(0.).lex_ord()), Ordering::Less);
+                            assert_eq!(d.lex_ord().cmp(&$id::splat(-0.).lex_ord()), Ordering::Greater);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
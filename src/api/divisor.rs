@@ -0,0 +1,213 @@
+//! Implementation of integer division by a runtime-invariant divisor.
+
+/// A precomputed integer divisor.
+///
+/// Dividing an integer vector with `/` or `%` lowers to one scalar division
+/// per lane on most targets. A `Divisor` precomputes a "magic number" from
+/// a scalar divisor once, such that dividing a vector with `T` lanes by it
+/// only requires a high-half multiplication, some additions and shifts (see
+/// [Granlund and Montgomery, 1994][gm]).
+///
+/// Division rounds towards zero and the remainder has the sign of the
+/// dividend, as for the integer `/` and `%` operators. Dividing `MIN` by
+/// `-1` wraps to `MIN`.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_simd_2::*;
+/// let d = Divisor::<u32>::new(7);
+/// let x = u32x4::new(0, 7, 100, u32::max_value());
+/// assert_eq!(x / d, u32x4::new(0, 1, 14, u32::max_value() / 7));
+/// assert_eq!(x % d, u32x4::new(0, 0, 2, u32::max_value() % 7));
+/// ```
+///
+/// [gm]: https://doi.org/10.1145/178243.178249
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::missing_inline_in_public_items)]
+pub struct Divisor<T> {
+    divisor: T,
+    magic: T,
+    shift0: u32,
+    shift1: u32,
+}
+
+impl<T: Copy> Divisor<T> {
+    /// Returns the divisor.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.divisor
+    }
+}
+
+macro_rules! impl_divisor {
+    (unsigned: $elem_ty:ident, $wide_ty:ident | $($id:ident[$test_tt:tt]),*) => {
+        impl Divisor<$elem_ty> {
+            /// Precomputes the divisor `d`.
+            ///
+            /// # Panics
+            ///
+            /// If `d` is zero.
+            #[inline]
+            pub fn new(d: $elem_ty) -> Self {
+                const BITS: u32 = crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                assert!(d != 0, "attempt to divide by zero");
+                // l = ceil(log2(d))
+                let l = BITS - (d - 1).leading_zeros();
+                // m = floor(2^N * (2^l - d) / d) + 1 < 2^N
+                let m = (((1 as $wide_ty) << l) - d as $wide_ty) << BITS;
+                let m = m / d as $wide_ty + 1;
+                Self {
+                    divisor: d,
+                    magic: m as $elem_ty,
+                    shift0: crate::cmp::min(l, 1),
+                    shift1: l.saturating_sub(1),
+                }
+            }
+        }
+
+        $(
+            impl crate::ops::Div<Divisor<$elem_ty>> for $id {
+                type Output = Self;
+                #[inline]
+                fn div(self, d: Divisor<$elem_ty>) -> Self {
+                    let t = self.mul_high($id::splat(d.magic));
+                    (t + ((self - t) >> d.shift0)) >> d.shift1
+                }
+            }
+
+            impl_divisor!(common: $elem_ty, $id[$test_tt]);
+        )*
+    };
+    (signed: $elem_ty:ident, $uelem_ty:ident, $uwide_ty:ident
+     | $($id:ident[$test_tt:tt]),*) => {
+        impl Divisor<$elem_ty> {
+            /// Precomputes the divisor `d`.
+            ///
+            /// # Panics
+            ///
+            /// If `d` is zero.
+            #[inline]
+            pub fn new(d: $elem_ty) -> Self {
+                const BITS: u32 = crate::mem::size_of::<$elem_ty>() as u32 * 8;
+                assert!(d != 0, "attempt to divide by zero");
+                let ad = d.wrapping_abs() as $uelem_ty;
+                // l = max(ceil(log2(|d|)), 1)
+                let l = crate::cmp::max(BITS - (ad - 1).leading_zeros(), 1);
+                // m = 1 + floor(2^(N + l - 1) / |d|). Truncating it to N
+                // bits produces the signed value m - 2^N. For |d| = 1 this
+                // is 1, for which the division below still produces the
+                // right result since it then wraps around consistently.
+                let m = 1 + ((1 as $uwide_ty) << (BITS + l - 1)) / ad as $uwide_ty;
+                Self {
+                    divisor: d,
+                    magic: m as $elem_ty,
+                    shift0: l - 1,
+                    shift1: BITS - 1,
+                }
+            }
+        }
+
+        $(
+            impl crate::ops::Div<Divisor<$elem_ty>> for $id {
+                type Output = Self;
+                #[inline]
+                fn div(self, d: Divisor<$elem_ty>) -> Self {
+                    let q = self + self.mul_high($id::splat(d.magic));
+                    // Round towards zero:
+                    let q = (q >> d.shift0) - (self >> d.shift1);
+                    // Negate the quotient if the divisor is negative:
+                    let s = $id::splat(d.divisor >> d.shift1);
+                    (q ^ s) - s
+                }
+            }
+
+            impl_divisor!(common: $elem_ty, $id[$test_tt]);
+        )*
+    };
+    (common: $elem_ty:ident, $id:ident[$test_tt:tt]) => {
+        impl crate::ops::Rem<Divisor<$elem_ty>> for $id {
+            type Output = Self;
+            #[inline]
+            fn rem(self, d: Divisor<$elem_ty>) -> Self {
+                self - (self / d) * $id::splat(d.divisor)
+            }
+        }
+
+        impl crate::ops::DivAssign<Divisor<$elem_ty>> for $id {
+            #[inline]
+            fn div_assign(&mut self, d: Divisor<$elem_ty>) {
+                *self = *self / d;
+            }
+        }
+
+        impl crate::ops::RemAssign<Divisor<$elem_ty>> for $id {
+            #[inline]
+            fn rem_assign(&mut self, d: Divisor<$elem_ty>) {
+                *self = *self % d;
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _divisor>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn div_rem() {
+                        let max = $elem_ty::max_value();
+                        let min = $elem_ty::min_value();
+                        let signed = min != 0;
+                        let mut values = [
+                            0, 1, 2, 3, 5, 7, 10, 64, 100, 641, 12345,
+                            max, max - 1, max / 2, max / 2 + 1, max / 3,
+                            min, min + 1, min / 2, min / 3,
+                        ];
+                        let n = values.len();
+                        if signed {
+                            for v in &mut values[n / 2..] {
+                                *v = (0 as $elem_ty).wrapping_sub(*v);
+                            }
+                        }
+
+                        for &d in values.iter().filter(|&&d| d != 0) {
+                            let div = Divisor::<$elem_ty>::new(d);
+                            assert_eq!(div.get(), d);
+                            for j in 0..n {
+                                let mut x = $id::splat(0);
+                                let mut q = $id::splat(0);
+                                let mut r = $id::splat(0);
+                                for i in 0..$id::lanes() {
+                                    let v = values[(i + j) % n];
+                                    x = x.replace(i, v);
+                                    q = q.replace(i, v.wrapping_div(d));
+                                    r = r.replace(i, v.wrapping_rem(d));
+                                }
+                                assert_eq!(x / div, q, "{:?} / {}", x, d);
+                                assert_eq!(x % div, r, "{:?} % {}", x, d);
+
+                                let mut y = x;
+                                y /= div;
+                                assert_eq!(y, q);
+                                let mut y = x;
+                                y %= div;
+                                assert_eq!(y, r);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::*;
+
+impl_divisor!(unsigned: u32, u64 | u32x2[test_v64], u32x4[test_v128],
+              u32x8[test_v256], u32x16[test_v512]);
+impl_divisor!(unsigned: u64, u128 | u64x2[test_v128], u64x4[test_v256],
+              u64x8[test_v512]);
+impl_divisor!(signed: i32, u32, u64 | i32x2[test_v64], i32x4[test_v128],
+              i32x8[test_v256], i32x16[test_v512]);
+impl_divisor!(signed: i64, u64, u128 | i64x2[test_v128], i64x4[test_v256],
+              i64x8[test_v512]);
@@ -1,17 +1,57 @@
 //! Implements vertical floating-point math operations.
+//!
+//! `sin`, `cos`, `exp` and `ln` also come in a `_fast` variant with a larger
+//! documented error bound. The other functions only have a single accuracy
+//! tier, and no function has a correctly-rounded variant.
 
 #[macro_use]
 mod abs;
 
+#[macro_use]
+mod acos;
+
+#[macro_use]
+mod acosh;
+
+#[macro_use]
+mod asin;
+
+#[macro_use]
+mod asinh;
+
+#[macro_use]
+mod atan;
+
+#[macro_use]
+mod atanh;
+
+#[macro_use]
+mod cbrt;
+
 #[macro_use]
 mod consts;
 
 #[macro_use]
 mod cos;
 
+#[macro_use]
+mod cosh;
+
+#[macro_use]
+mod erf;
+
 #[macro_use]
 mod exp;
 
+#[macro_use]
+mod frexp;
+
+#[macro_use]
+mod gamma;
+
+#[macro_use]
+mod hypot;
+
 #[macro_use]
 mod powf;
 
@@ -30,9 +70,15 @@ mod recpre;
 #[macro_use]
 mod rsqrte;
 
+#[macro_use]
+mod round;
+
 #[macro_use]
 mod sin;
 
+#[macro_use]
+mod sinh;
+
 #[macro_use]
 mod sqrt;
 
@@ -42,6 +88,9 @@ mod sqrte;
 #[macro_use]
 mod tanh;
 
+#[macro_use]
+mod ulp;
+
 macro_rules! impl_float_category {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident) => {
         impl $id {
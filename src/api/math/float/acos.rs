@@ -0,0 +1,50 @@
+//! Implements vertical (lane-wise) floating-point `acos`.
+
+macro_rules! impl_math_float_acos {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Arccosine.
+            ///
+            /// Returns values in `[0, PI]`, and NaN for lanes outside of
+            /// `[-1, 1]`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn acos(self) -> Self {
+                use crate::codegen::math::float::acos::Acos;
+                Acos::acos(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_acos>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn acos() {
+                        use crate::$elem_ty::consts::{FRAC_PI_2, PI};
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(z, o.acos());
+                        assert_eq!($id::splat(PI), (-o).acos());
+                        assert_eq!($id::splat(FRAC_PI_2), z.acos());
+                        assert!($id::splat(-1.5).acos().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).acos().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            0.5, -0.5, 0.4375, 0.625, 0.975, -0.975, 1e-20,
+                            -1e-20, 1.5, crate::$elem_ty::NEG_INFINITY,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: acos, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,46 @@
+//! Implements vertical (lane-wise) floating-point `acosh`.
+
+macro_rules! impl_math_float_acosh {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Inverse hyperbolic cosine.
+            ///
+            /// Returns NaN for lanes less than `1`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn acosh(self) -> Self {
+                use crate::codegen::math::float::acosh::Acosh;
+                Acosh::acosh(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_acosh>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn acosh() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, o.acosh());
+                        assert_eq!(inf, inf.acosh());
+                        assert!(z.acosh().is_nan().all());
+                        assert!((-o).acosh().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).acosh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [1.000_001, 1.999, 2., 4096., 1e10, 1e30, crate::$elem_ty::MAX];
+                        let values = (0..=N)
+                            .map(|k| 1. + 16. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: acosh, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
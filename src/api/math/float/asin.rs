@@ -0,0 +1,52 @@
+//! Implements vertical (lane-wise) floating-point `asin`.
+
+macro_rules! impl_math_float_asin {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Arcsine.
+            ///
+            /// Returns values in `[-PI/2, PI/2]`, and NaN for lanes outside
+            /// of `[-1, 1]`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn asin(self) -> Self {
+                use crate::codegen::math::float::asin::Asin;
+                Asin::asin(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_asin>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn asin() {
+                        use crate::$elem_ty::consts::FRAC_PI_2;
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(z, z.asin());
+                        assert_eq!($id::splat(FRAC_PI_2), o.asin());
+                        assert_eq!($id::splat(-FRAC_PI_2), (-o).asin());
+                        assert!($id::splat(1.5).asin().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).asin().is_nan().all());
+                        assert!((-z).asin().extract(0).is_sign_negative());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            0.5, -0.5, 0.4375, 0.625, 0.975, 1e-20, -1e-20,
+                            crate::$elem_ty::MIN_POSITIVE, -1.5,
+                            crate::$elem_ty::INFINITY,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: asin, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
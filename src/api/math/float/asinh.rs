@@ -0,0 +1,46 @@
+//! Implements vertical (lane-wise) floating-point `asinh`.
+
+macro_rules! impl_math_float_asinh {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Inverse hyperbolic sine.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn asinh(self) -> Self {
+                use crate::codegen::math::float::asinh::Asinh;
+                Asinh::asinh(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_asinh>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn asinh() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.asinh());
+                        assert!((-z).asinh().extract(0).is_sign_negative());
+                        assert_eq!(inf, inf.asinh());
+                        assert_eq!(-inf, (-inf).asinh());
+                        assert!($id::splat(crate::$elem_ty::NAN).asinh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            1e-20, -1e-20, 1.999, 2., 4096., 1e10, -1e10, 1e30,
+                            crate::$elem_ty::MAX, crate::$elem_ty::MIN,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -8. + 16. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: asinh, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
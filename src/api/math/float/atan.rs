@@ -0,0 +1,115 @@
+//! Implements vertical (lane-wise) floating-point `atan` and `atan2`.
+
+macro_rules! impl_math_float_atan {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Arctangent.
+            ///
+            /// Returns values in `[-PI/2, PI/2]`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn atan(self) -> Self {
+                use crate::codegen::math::float::atan::Atan;
+                Atan::atan(self)
+            }
+
+            /// Four-quadrant arctangent of `self / x`.
+            ///
+            /// Returns the angle in `[-PI, PI]` between the positive x-axis
+            /// and the point `(x, self)`. Signed zeros and infinities are
+            /// handled like in C's `atan2`, e.g., `atan2(-0, -1)` is `-PI`
+            /// and `atan2(inf, -inf)` is `3*PI/4`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn atan2(self, x: Self) -> Self {
+                use crate::codegen::math::float::atan2::Atan2;
+                Atan2::atan2(self, x)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_atan>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn atan() {
+                        use crate::$elem_ty::consts::{FRAC_PI_2, FRAC_PI_4};
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.atan());
+                        assert!((-z).atan().extract(0).is_sign_negative());
+                        assert_eq!($id::splat(FRAC_PI_4), o.atan());
+                        assert_eq!($id::splat(FRAC_PI_2), inf.atan());
+                        assert_eq!($id::splat(-FRAC_PI_2), (-inf).atan());
+                        assert!($id::splat(crate::$elem_ty::NAN).atan().is_nan().all());
+
+                        // 1e-30, 1.1e-30, ..., 1e30 with alternating signs
+                        let values = crate::iter::successors(
+                            Some(1e-30 as $elem_ty), |x| Some(x * 1.01)
+                        )
+                            .take_while(|x| *x < 1e30)
+                            .enumerate()
+                            .map(|(k, x)| if k % 2 == 0 { x } else { -x })
+                            .chain([
+                                0.4375, 0.6875, 1.1875, 2.4375, -2.4375,
+                                crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MAX,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: atan, values, 4);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn atan2() {
+                        use crate::$elem_ty::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        let nan = $id::splat(crate::$elem_ty::NAN);
+
+                        // quadrant edge cases
+                        assert_eq!(z.atan2(z), z);
+                        assert!((-z).atan2(z).extract(0).is_sign_negative());
+                        assert_eq!(z.atan2(-z), $id::splat(PI));
+                        assert_eq!((-z).atan2(-z), $id::splat(-PI));
+                        assert_eq!(z.atan2(-o), $id::splat(PI));
+                        assert_eq!((-z).atan2(-o), $id::splat(-PI));
+                        assert_eq!(o.atan2(z), $id::splat(FRAC_PI_2));
+                        assert_eq!(o.atan2(-z), $id::splat(FRAC_PI_2));
+                        assert_eq!((-o).atan2(z), $id::splat(-FRAC_PI_2));
+                        assert_eq!(o.atan2(inf), z);
+                        assert_eq!(o.atan2(-inf), $id::splat(PI));
+                        assert_eq!((-o).atan2(-inf), $id::splat(-PI));
+                        assert_eq!(inf.atan2(o), $id::splat(FRAC_PI_2));
+                        assert_eq!(inf.atan2(inf), $id::splat(FRAC_PI_4));
+                        assert!(nan.atan2(z).is_nan().all());
+                        assert!(z.atan2(nan).is_nan().all());
+                        assert!(inf.atan2(nan).is_nan().all());
+
+                        const S: [$elem_ty; 18] = [
+                            0., -0., 1e-30, -1e-30, 0.5, -0.5, 1., -1., 2.,
+                            -2., 3., -3., 1e30, -1e30,
+                            crate::$elem_ty::INFINITY,
+                            crate::$elem_ty::NEG_INFINITY,
+                            crate::$elem_ty::NAN,
+                            123.456,
+                        ];
+                        let values = S.iter()
+                            .flat_map(|&y| S.iter().map(move |&x| (y, x)))
+                            .chain((-30..=30).flat_map(|i| {
+                                (-30..=30).map(move |j| {
+                                    (i as $elem_ty * 0.37, j as $elem_ty * 0.53)
+                                })
+                            }));
+                        test_ulp_binary!($id[$elem_ty]: atan2, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,48 @@
+//! Implements vertical (lane-wise) floating-point `atanh`.
+
+macro_rules! impl_math_float_atanh {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Inverse hyperbolic tangent.
+            ///
+            /// Returns `±INFINITY` for lanes equal to `±1`, and NaN for lanes
+            /// outside of `[-1, 1]`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn atanh(self) -> Self {
+                use crate::codegen::math::float::atanh::Atanh;
+                Atanh::atanh(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_atanh>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn atanh() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.atanh());
+                        assert!((-z).atanh().extract(0).is_sign_negative());
+                        assert_eq!(inf, o.atanh());
+                        assert_eq!(-inf, (-o).atanh());
+                        assert!($id::splat(1.5).atanh().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).atanh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [1e-20, -1e-20, 0.499, 0.5, 0.999_9, -0.999_9];
+                        let values = (1..N)
+                            .map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: atanh, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,48 @@
+//! Implements vertical (lane-wise) floating-point `cbrt`.
+
+macro_rules! impl_math_float_cbrt {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Cube root.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1 ULP.
+            #[inline]
+            pub fn cbrt(self) -> Self {
+                use crate::codegen::math::float::cbrt::Cbrt;
+                Cbrt::cbrt(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_cbrt>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn cbrt() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.cbrt());
+                        assert!((-z).cbrt().extract(0).is_sign_negative());
+                        assert_eq!($id::splat(3.), $id::splat(27.).cbrt());
+                        assert_eq!($id::splat(-2.), $id::splat(-8.).cbrt());
+                        assert_eq!(inf, inf.cbrt());
+                        assert_eq!(-inf, (-inf).cbrt());
+                        assert!($id::splat(crate::$elem_ty::NAN).cbrt().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain([
+                                1e-30, 1e30, crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MAX, crate::$elem_ty::MIN,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: cbrt, values, 2);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -4,12 +4,24 @@ macro_rules! impl_math_float_cos {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Cosine.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn cos(self) -> Self {
                 use crate::codegen::math::float::cos::Cos;
                 Cos::cos(self)
             }
 
+            /// Cosine, with a larger error bound than [`cos`](#method.cos).
+            ///
+            /// The maximum error is 4 ULP.
+            #[inline]
+            pub fn cos_fast(self) -> Self {
+                use crate::codegen::math::float::cos_fast::CosFast;
+                CosFast::cos_fast(self)
+            }
+
             /// Cosine of `self * PI`.
             #[inline]
             pub fn cos_pi(self) -> Self {
@@ -36,6 +48,37 @@ macro_rules! impl_math_float_cos {
                         assert_eq!(o, z.cos());
                         assert_eq!(z_r, ph.cos());
                         assert_eq!(o_r, p.cos());
+                        assert!($id::splat(crate::$elem_ty::INFINITY).cos().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).cos().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            1e-20, -1e-20, 1e4, -1e4, 1e6, 1e10, -1e10,
+                            crate::$elem_ty::MIN_POSITIVE, crate::$elem_ty::MAX,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: cos, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn cos_fast() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(o, z.cos_fast());
+                        assert!($id::splat(crate::$elem_ty::INFINITY).cos_fast().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).cos_fast().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            1e-20, -1e-20, 8192., -8192., 8193., 1e6, 1e10,
+                            crate::$elem_ty::MIN_POSITIVE, crate::$elem_ty::MAX,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: cos_fast => cos, values, 4);
                     }
                 }
             }
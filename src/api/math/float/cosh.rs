@@ -0,0 +1,45 @@
+//! Implements vertical (lane-wise) floating-point `cosh`.
+
+macro_rules! impl_math_float_cosh {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Hyperbolic cosine.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn cosh(self) -> Self {
+                use crate::codegen::math::float::cosh::Cosh;
+                Cosh::cosh(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_cosh>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn cosh() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(o, z.cosh());
+                        assert_eq!(o, (-z).cosh());
+                        assert_eq!(inf, inf.cosh());
+                        assert_eq!(inf, (-inf).cosh());
+                        assert!($id::splat(crate::$elem_ty::NAN).cosh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let max = crate::$elem_ty::MAX.ln() + 0.6;
+                        let specials = [1e-20, max, -max, 1e3];
+                        let values = (0..=N)
+                            .map(|k| -20. + 40. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: cosh, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
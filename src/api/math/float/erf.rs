@@ -0,0 +1,76 @@
+//! Implements vertical (lane-wise) floating-point `erf` and `erfc`.
+
+macro_rules! impl_math_float_erf {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Error function.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1 ULP.
+            #[inline]
+            pub fn erf(self) -> Self {
+                use crate::codegen::math::float::erf::Erf;
+                Erf::erf(self)
+            }
+
+            /// Complementary error function, `1 - erf(self)`, computed without
+            /// the cancellation of the subtraction for large lanes.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1.5 ULP.
+            #[inline]
+            pub fn erfc(self) -> Self {
+                use crate::codegen::math::float::erfc::Erfc;
+                Erfc::erfc(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_erf>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn erf() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.erf());
+                        assert!((-z).erf().extract(0).is_sign_negative());
+                        assert_eq!(o, inf.erf());
+                        assert_eq!(-o, (-inf).erf());
+                        assert!($id::splat(crate::$elem_ty::NAN).erf().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -6. + 12. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain([1e-20, -1e-20, 30., -30.].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: erf, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn erfc() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(o, z.erfc());
+                        assert_eq!(z, inf.erfc());
+                        assert_eq!($id::splat(2.), (-inf).erfc());
+                        assert!($id::splat(crate::$elem_ty::NAN).erfc().is_nan().all());
+
+                        // far in the tail, where `1 - erf(x)` would be zero:
+                        assert!($id::splat(5.).erfc().gt(z).all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -6. + 15. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain([1e-20, -1e-20].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: erfc, values, 3);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -1,14 +1,50 @@
-//! Implements vertical (lane-wise) floating-point `exp`.
+//! Implements vertical (lane-wise) floating-point `exp`, `exp2`, `exp10` and
+//! `exp_m1`.
 
 macro_rules! impl_math_float_exp {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Returns the exponential function of `self`: `e^(self)`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn exp(self) -> Self {
                 use crate::codegen::math::float::exp::Exp;
                 Exp::exp(self)
             }
+
+            /// Returns `e^(self)`, with a larger error bound than
+            /// [`exp`](#method.exp).
+            ///
+            /// The maximum error is 4 ULP.
+            #[inline]
+            pub fn exp_fast(self) -> Self {
+                use crate::codegen::math::float::exp_fast::ExpFast;
+                ExpFast::exp_fast(self)
+            }
+
+            /// Returns `2^(self)`.
+            #[inline]
+            pub fn exp2(self) -> Self {
+                use crate::codegen::math::float::exp2::Exp2;
+                Exp2::exp2(self)
+            }
+
+            /// Returns `10^(self)`.
+            #[inline]
+            pub fn exp10(self) -> Self {
+                use crate::codegen::math::float::exp10::Exp10;
+                Exp10::exp10(self)
+            }
+
+            /// Returns `e^(self) - 1` in a way that is accurate even if
+            /// `self` is close to zero.
+            #[inline]
+            pub fn exp_m1(self) -> Self {
+                use crate::codegen::math::float::exp_m1::ExpM1;
+                ExpM1::exp_m1(self)
+            }
         }
 
         test_if!{
@@ -25,6 +61,100 @@ macro_rules! impl_math_float_exp {
                         let e = $id::splat(crate::f64::consts::E as $elem_ty);
                         let tol = $id::splat(2.4e-4 as $elem_ty);
                         assert!((e - o.exp()).abs().le(tol).all());
+
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(inf, inf.exp());
+                        assert_eq!(z, (-inf).exp());
+                        assert!($id::splat(crate::$elem_ty::NAN).exp().is_nan().all());
+
+                        // covers overflow, and subnormal and zero results:
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -800. + 1600. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((0..=N).map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty)))
+                            .chain([1e-20, -1e-20].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: exp, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn exp_fast() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(o, z.exp_fast());
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(inf, inf.exp_fast());
+                        assert_eq!(z, (-inf).exp_fast());
+                        assert!($id::splat(crate::$elem_ty::NAN).exp_fast().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -800. + 1600. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((0..=N).map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty)))
+                            .chain([1e-20, -1e-20].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: exp_fast => exp, values, 4);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn exp2() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(o, z.exp2());
+                        assert_eq!($id::splat(8.), $id::splat(3.).exp2());
+                        assert_eq!($id::splat(0.25), $id::splat(-2.).exp2());
+                        assert_eq!(z, $id::splat(crate::$elem_ty::NEG_INFINITY).exp2());
+                        assert!($id::splat(crate::$elem_ty::NAN).exp2().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty));
+                        test_ulp_unary!($id[$elem_ty]: exp2, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn exp10() {
+                        use crate::testing::utils::UlpDiff;
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(o, z.exp10());
+                        assert_eq!($id::splat(1000.), $id::splat(3.).exp10());
+                        assert_eq!(z, $id::splat(crate::$elem_ty::NEG_INFINITY).exp10());
+                        assert!($id::splat(crate::$elem_ty::NAN).exp10().is_nan().all());
+
+                        const N: usize = 4096;
+                        let mut x = z;
+                        for k in 0..=N {
+                            let v = -30. + 60. * (k as $elem_ty) / (N as $elem_ty);
+                            let i = k % $id::lanes();
+                            x = x.replace(i, v);
+                            let r = x.exp10().extract(i);
+                            let e = libm_ref!($elem_ty: pow(10., v));
+                            assert!(
+                                r.ulp_diff(e) <= 2,
+                                "exp10({:e}) = {:e}, expected {:e}", v, r, e
+                            );
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn exp_m1() {
+                        let z = $id::splat(0 as $elem_ty);
+                        assert_eq!(z, z.exp_m1());
+                        assert!((-z).exp_m1().extract(0).is_sign_negative());
+                        assert_eq!($id::splat(-1.), $id::splat(crate::$elem_ty::NEG_INFINITY).exp_m1());
+                        assert!($id::splat(crate::$elem_ty::NAN).exp_m1().is_nan().all());
+
+                        // small arguments, where `exp(x) - 1` loses all
+                        // precision:
+                        let tiny = $id::splat(1e-10);
+                        assert_eq!(tiny, tiny.exp_m1());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -1. + 2. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((1..30).map(|k| (2 as $elem_ty).powi(-k)))
+                            .chain((1..30).map(|k| -(2 as $elem_ty).powi(-k)))
+                            .chain([-20., 10., 50.].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: exp_m1 => expm1, values, 2);
                     }
                 }
             }
@@ -0,0 +1,173 @@
+//! Implements vertical (lane-wise) floating-point `frexp`, `ldexp`, `scalbn`
+//! and `ilogb`.
+
+macro_rules! impl_math_float_frexp {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $iid:ident | $test_tt:tt) => {
+        impl $id {
+            /// Splits each lane into a normalized fraction and an integral
+            /// power of two.
+            ///
+            /// Returns `(m, e)` such that `self == m * 2^e` with
+            /// `0.5 <= |m| < 1`. Zeros, infinities and NaNs are returned
+            /// unchanged in `m`, with `e == 0`.
+            #[inline]
+            pub fn frexp(self) -> (Self, $iid) {
+                use crate::mem::transmute;
+                const MANT: u32 = crate::$elem_ty::MANTISSA_DIGITS - 1;
+                const MAX_EXP: i32 = crate::$elem_ty::MAX_EXP;
+
+                // Subnormals are scaled by 2^64 to make them normal:
+                let z = Self::splat(0.);
+                let sub = self.ne(z) & self.abs().lt(Self::splat(crate::$elem_ty::MIN_POSITIVE));
+                let x = sub.select(self * Self::splat(18_446_744_073_709_551_616.), self);
+                let adj = sub.select($iid::splat(64), $iid::splat(0));
+
+                let bits: $iid = unsafe { transmute(x) };
+                let exp_mask = $iid::splat((2 * MAX_EXP - 1) as _) << MANT;
+                let e = ((bits & exp_mask) >> MANT) - $iid::splat((MAX_EXP - 2) as _) - adj;
+                let m = (bits & !exp_mask) | ($iid::splat((MAX_EXP - 2) as _) << MANT);
+                let m: Self = unsafe { transmute(m) };
+
+                let special = self.eq(z) | !self.is_finite();
+                (special.select(self, m), special.select($iid::splat(0), e))
+            }
+
+            /// Multiplies each lane by `2^n`.
+            ///
+            /// The result is correctly rounded: it only differs from
+            /// `self * 2^n` computed with infinite precision when it
+            /// overflows or is subnormal.
+            #[inline]
+            pub fn ldexp(self, n: $iid) -> Self {
+                use crate::mem::transmute;
+                const MANT: u32 = crate::$elem_ty::MANTISSA_DIGITS - 1;
+                const BIAS: i32 = crate::$elem_ty::MAX_EXP - 1;
+                const EMIN: i32 = crate::$elem_ty::MIN_EXP - 1;
+                // The scale applied by the steps towards zero keeps the
+                // intermediate results normal, so that the result is only
+                // rounded once, as in musl's `scalbn`:
+                const DOWN: i32 = EMIN + crate::$elem_ty::MANTISSA_DIGITS as i32;
+                let pow2 = |k: $iid| -> Self {
+                    unsafe { transmute((k + $iid::splat(BIAS as _)) << MANT) }
+                };
+
+                let mut y = self;
+                let mut n = n;
+                for _ in 0..2 {
+                    let up = n.gt($iid::splat(BIAS as _));
+                    y = up.select(y * pow2($iid::splat(BIAS as _)), y);
+                    n = up.select(n - $iid::splat(BIAS as _), n);
+                    let down = n.lt($iid::splat(EMIN as _));
+                    y = down.select(y * pow2($iid::splat(DOWN as _)), y);
+                    n = down.select(n - $iid::splat(DOWN as _), n);
+                }
+                let n = n.max($iid::splat(EMIN as _)).min($iid::splat(BIAS as _));
+                y * pow2(n)
+            }
+
+            /// Multiplies each lane by `2^n`.
+            ///
+            /// This is the same as [`ldexp`](#method.ldexp), since the radix
+            /// of the floating-point types is two.
+            #[inline]
+            pub fn scalbn(self, n: $iid) -> Self {
+                self.ldexp(n)
+            }
+
+            /// Unbiased exponent of each lane.
+            ///
+            /// This is the exponent `e` such that `1 <= |self| / 2^e < 2`,
+            /// including for subnormal lanes. Lanes that are zero or NaN
+            /// return the minimum value of the integer lanes, and infinite
+            /// lanes return its maximum value.
+            #[inline]
+            pub fn ilogb(self) -> $iid {
+                let min = $iid::splat(1) << (crate::mem::size_of::<$elem_ty>() as u32 * 8 - 1);
+                let (_, e) = self.frexp();
+                let e = self.is_infinite().select(!min, e - $iid::splat(1));
+                (self.eq(Self::splat(0.)) | self.is_nan()).select(min, e)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_frexp>] {
+                    use super::*;
+                    use crate::testing::utils::BitEq;
+
+                    fn values() -> [$elem_ty; 20] {
+                        [
+                            0., -0., 1., -1., 0.5, 0.75, -3., 1e-3, 1e10, -1e30,
+                            crate::$elem_ty::EPSILON,
+                            crate::$elem_ty::MIN_POSITIVE,
+                            crate::$elem_ty::MIN_POSITIVE / 3.,
+                            -crate::$elem_ty::MIN_POSITIVE / 1e6,
+                            $elem_ty::from_bits(1),
+                            crate::$elem_ty::MAX,
+                            crate::$elem_ty::MIN,
+                            crate::$elem_ty::INFINITY,
+                            crate::$elem_ty::NEG_INFINITY,
+                            crate::$elem_ty::NAN,
+                        ]
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn frexp() {
+                        for &v in values().iter() {
+                            let (m, e) = $id::splat(v).frexp();
+                            let (em, ee) = libm_ref!($elem_ty: frexp(v));
+                            for i in 0..$id::lanes() {
+                                let m = m.extract(i);
+                                assert!(
+                                    m.bit_eq(em),
+                                    "frexp({:e}).0 = {:e}, expected {:e}", v, m, em
+                                );
+                            }
+                            assert_eq!(e, $iid::splat(ee as _), "frexp({:e}).1", v);
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ldexp() {
+                        let exponents = [
+                            0, 1, -1, 10, -10, 100, -100, 200, -200, 1000, -1000,
+                            1100, -1100, 2100, -2100, 1 << 30, -(1 << 30),
+                        ];
+                        for &v in values().iter() {
+                            for &n in exponents.iter() {
+                                let r = $id::splat(v).ldexp($iid::splat(n as _));
+                                let s = $id::splat(v).scalbn($iid::splat(n as _));
+                                let e = libm_ref!($elem_ty: ldexp(v, n));
+                                for i in 0..$id::lanes() {
+                                    let (r, s) = (r.extract(i), s.extract(i));
+                                    assert!(
+                                        r.bit_eq(e),
+                                        "ldexp({:e}, {}) = {:e}, expected {:e}", v, n, r, e
+                                    );
+                                    assert_eq!(r.to_bits(), s.to_bits());
+                                }
+                            }
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ilogb() {
+                        let min = $iid::splat(1) << (mem::size_of::<$elem_ty>() as u32 * 8 - 1);
+                        for &v in values().iter() {
+                            let r = $id::splat(v).ilogb();
+                            if v == 0. || v.is_nan() {
+                                assert_eq!(r, min);
+                            } else if v.is_infinite() {
+                                assert_eq!(r, !min);
+                            } else {
+                                let e = libm_ref!($elem_ty: ilogb(v));
+                                assert_eq!(r, $iid::splat(e as _), "ilogb({:e})", v);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,79 @@
+//! Implements vertical (lane-wise) floating-point `tgamma` and `lgamma`.
+
+macro_rules! impl_math_float_gamma {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Gamma function.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1 ULP.
+            #[inline]
+            pub fn tgamma(self) -> Self {
+                use crate::codegen::math::float::tgamma::Tgamma;
+                Tgamma::tgamma(self)
+            }
+
+            /// Natural logarithm of the absolute value of the gamma function.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1 ULP.
+            #[inline]
+            pub fn lgamma(self) -> Self {
+                use crate::codegen::math::float::lgamma::Lgamma;
+                Lgamma::lgamma(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_gamma>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn tgamma() {
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(o, o.tgamma());
+                        assert_eq!(o, $id::splat(2.).tgamma());
+                        assert_eq!($id::splat(24.), $id::splat(5.).tgamma());
+                        assert_eq!(inf, inf.tgamma());
+                        assert!($id::splat(-1.).tgamma().is_nan().all());
+                        assert!((-inf).tgamma().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).tgamma().is_nan().all());
+
+                        // Avoids the neighbourhood of the poles at the non-positive integers:
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| 0.01 + 30. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((1..10).map(|k| 0.5 - k as $elem_ty))
+                            .chain([1e-20, 200.].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: tgamma, values, 3);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn lgamma() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, o.lgamma());
+                        assert_eq!(z, $id::splat(2.).lgamma());
+                        assert_eq!(inf, z.lgamma());
+                        assert_eq!(inf, inf.lgamma());
+                        assert!($id::splat(crate::$elem_ty::NAN).lgamma().is_nan().all());
+
+                        // Avoids the zeros at 1 and 2, where the relative error is unbounded:
+                        const N: usize = 4096;
+                        let values = (1..N)
+                            .map(|k| 0.9 * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((0..=N).map(|k| 2.5 + 1000. * (k as $elem_ty) / (N as $elem_ty)))
+                            .chain((1..10).map(|k| 0.5 - k as $elem_ty))
+                            .chain([1e-20, 1e20].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: lgamma, values, 3);
+                    }
+                }
+            }
+        }
+    };
+}
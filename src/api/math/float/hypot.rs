@@ -0,0 +1,58 @@
+//! Implements vertical (lane-wise) floating-point `hypot`.
+
+macro_rules! impl_math_float_hypot {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Length of the hypotenuse of a right-angle triangle with legs of
+            /// length `self` and `y`, `sqrt(self^2 + y^2)`, without undue
+            /// overflow or underflow in the intermediate results.
+            ///
+            /// The result is the same as that of the scalar `libm` function,
+            /// unless the `sleef-sys` backend is used, in which case the
+            /// maximum error is 1 ULP.
+            #[inline]
+            pub fn hypot(self, y: Self) -> Self {
+                use crate::codegen::math::float::hypot::Hypot;
+                Hypot::hypot(self, y)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_hypot>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn hypot() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        let nan = $id::splat(crate::$elem_ty::NAN);
+                        assert_eq!(z, z.hypot(z));
+                        assert_eq!($id::splat(5.), $id::splat(3.).hypot($id::splat(-4.)));
+                        assert_eq!(inf, inf.hypot(nan));
+                        assert_eq!(inf, nan.hypot(-inf));
+                        assert!(nan.hypot(z).is_nan().all());
+
+                        // the intermediate squares would overflow or underflow:
+                        let max = crate::$elem_ty::MAX;
+                        assert_eq!($id::splat(max), $id::splat(max).hypot(z));
+                        let min = crate::$elem_ty::MIN_POSITIVE;
+                        assert!($id::splat(min).hypot($id::splat(min)).gt(z).all());
+
+                        const N: usize = 64;
+                        let specials = [(1e30, 1e-30), (1e-30, 1e-30), (max / 2., max / 2.)];
+                        let values = (0..=N)
+                            .flat_map(|i| (0..=N).map(move |j| (i, j)))
+                            .map(|(i, j)| {
+                                let a = -100. + 200. * (i as $elem_ty) / (N as $elem_ty);
+                                let b = -100. + 200. * (j as $elem_ty) / (N as $elem_ty);
+                                (a, b)
+                            })
+                            .chain(specials.iter().cloned());
+                        test_ulp_binary!($id[$elem_ty]: hypot, values, 1);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -1,14 +1,52 @@
-//! Implements vertical (lane-wise) floating-point `ln`.
+//! Implements vertical (lane-wise) floating-point `ln`, `log2`, `log10` and
+//! `ln_1p`.
 
 macro_rules! impl_math_float_ln {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Returns the natural logarithm of `self`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn ln(self) -> Self {
                 use crate::codegen::math::float::ln::Ln;
                 Ln::ln(self)
             }
+
+            /// Returns the natural logarithm of `self`, with a larger error
+            /// bound than [`ln`](#method.ln).
+            ///
+            /// The maximum error is 4 ULP. Unless the `sleef-sys` backend is
+            /// used, this is [`ln`](#method.ln) for `f64` lanes, because a
+            /// faster approximation would need a much longer polynomial.
+            #[inline]
+            pub fn ln_fast(self) -> Self {
+                use crate::codegen::math::float::ln_fast::LnFast;
+                LnFast::ln_fast(self)
+            }
+
+            /// Returns the base 2 logarithm of `self`.
+            #[inline]
+            pub fn log2(self) -> Self {
+                use crate::codegen::math::float::log2::Log2;
+                Log2::log2(self)
+            }
+
+            /// Returns the base 10 logarithm of `self`.
+            #[inline]
+            pub fn log10(self) -> Self {
+                use crate::codegen::math::float::log10::Log10;
+                Log10::log10(self)
+            }
+
+            /// Returns `ln(1 + self)` in a way that is accurate even if
+            /// `self` is close to zero.
+            #[inline]
+            pub fn ln_1p(self) -> Self {
+                use crate::codegen::math::float::ln_1p::Ln1p;
+                Ln1p::ln_1p(self)
+            }
         }
 
         test_if!{
@@ -25,6 +63,107 @@ macro_rules! impl_math_float_ln {
                         let e = $id::splat(crate::f64::consts::E as $elem_ty);
                         let tol = $id::splat(2.4e-4 as $elem_ty);
                         assert!((o - e.ln()).abs().le(tol).all());
+
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(inf, inf.ln());
+                        assert_eq!(-inf, z.ln());
+                        assert!($id::splat(-1.).ln().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).ln().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (1..=N)
+                            .map(|k| (k as $elem_ty) / 64.)
+                            .chain((0..=N).map(|k| 0.5 + (k as $elem_ty) / (N as $elem_ty)))
+                            .chain([
+                                1e-30, 1e30, crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MIN_POSITIVE / 4.,
+                                crate::$elem_ty::MAX,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: ln => log, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ln_fast() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(z, o.ln_fast());
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(inf, inf.ln_fast());
+                        assert_eq!(-inf, z.ln_fast());
+                        assert!($id::splat(-1.).ln_fast().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).ln_fast().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (1..=N)
+                            .map(|k| (k as $elem_ty) / 64.)
+                            .chain((0..=N).map(|k| 0.5 + (k as $elem_ty) / (N as $elem_ty)))
+                            .chain([
+                                1e-30, 1e30, crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MIN_POSITIVE / 4.,
+                                crate::$elem_ty::MAX,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: ln_fast => log, values, 4);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn log2() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(z, o.log2());
+                        assert_eq!($id::splat(3.), $id::splat(8.).log2());
+                        assert_eq!($id::splat(-2.), $id::splat(0.25).log2());
+                        assert_eq!($id::splat(crate::$elem_ty::NEG_INFINITY), z.log2());
+                        assert!($id::splat(-1.).log2().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (1..=N)
+                            .map(|k| (k as $elem_ty) / 64.)
+                            .chain([
+                                1e-30, 1e30, crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MAX, crate::$elem_ty::INFINITY,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: log2, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn log10() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        assert_eq!(z, o.log10());
+                        assert_eq!($id::splat(2.), $id::splat(100.).log10());
+                        assert_eq!($id::splat(crate::$elem_ty::NEG_INFINITY), z.log10());
+                        assert!($id::splat(-1.).log10().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (1..=N)
+                            .map(|k| (k as $elem_ty) / 64.)
+                            .chain([
+                                1e-30, 1e30, crate::$elem_ty::MIN_POSITIVE,
+                                crate::$elem_ty::MAX, crate::$elem_ty::INFINITY,
+                            ].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: log10, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ln_1p() {
+                        let z = $id::splat(0 as $elem_ty);
+                        assert_eq!(z, z.ln_1p());
+                        assert!((-z).ln_1p().extract(0).is_sign_negative());
+                        assert_eq!($id::splat(crate::$elem_ty::NEG_INFINITY), $id::splat(-1.).ln_1p());
+                        assert!($id::splat(-2.).ln_1p().is_nan().all());
+
+                        // small arguments, where `ln(1 + x)` loses all
+                        // precision:
+                        let tiny = $id::splat(1e-10);
+                        assert_eq!(tiny, tiny.ln_1p());
+
+                        const N: usize = 4096;
+                        let values = (0..N)
+                            .map(|k| -0.99 + 10. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((1..30).map(|k| (2 as $elem_ty).powi(-k)))
+                            .chain((1..30).map(|k| -(2 as $elem_ty).powi(-k)))
+                            .chain([1e10, crate::$elem_ty::INFINITY].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: ln_1p => log1p, values, 2);
                     }
                 }
             }
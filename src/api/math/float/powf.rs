@@ -4,6 +4,9 @@ macro_rules! impl_math_float_powf {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Raises `self` number to the floating point power of `x`.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn powf(self, x: Self) -> Self {
                 use crate::codegen::math::float::powf::Powf;
@@ -28,6 +31,37 @@ macro_rules! impl_math_float_powf {
 
                         let f = $id::splat(4 as $elem_ty);
                         assert_eq!(f, t.powf(t));
+
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        let nan = $id::splat(crate::$elem_ty::NAN);
+                        assert_eq!(o, nan.powf(z));
+                        assert_eq!(o, o.powf(nan));
+                        assert!(nan.powf(o).is_nan().all());
+                        assert!((-t).powf($id::splat(0.5)).is_nan().all());
+                        assert_eq!($id::splat(-8.), (-t).powf($id::splat(3.)));
+                        assert_eq!(f, (-t).powf(t));
+                        assert_eq!(inf, z.powf(-o));
+                        assert_eq!(-inf, (-z).powf(-o));
+                        assert_eq!(z, $id::splat(0.5).powf(inf));
+                        assert_eq!(inf, t.powf(inf));
+                        assert_eq!(o, (-o).powf(inf));
+
+                        const N: usize = 64;
+                        let values = (1..=N).flat_map(|i| {
+                            let x = (i as $elem_ty) / 8.;
+                            (0..=N).map(move |j| {
+                                (x, -40. + 80. * (j as $elem_ty) / (N as $elem_ty))
+                            })
+                        });
+                        test_ulp_binary!($id[$elem_ty]: powf => pow, values, 2);
+
+                        let specials = [
+                            (-2., 3.), (-2., 4.), (-0.5, -5.), (10., 300.),
+                            (10., -300.), (1.5, 1e5), (0.999, 1e6), (1e-5, 40.),
+                        ];
+                        test_ulp_binary!(
+                            $id[$elem_ty]: powf => pow, specials.iter().cloned(), 2
+                        );
                     }
                 }
             }
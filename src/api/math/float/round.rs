@@ -0,0 +1,134 @@
+//! Implements vertical (lane-wise) floating-point rounding.
+
+macro_rules! impl_math_float_round {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Largest integer less than or equal to each lane.
+            #[inline]
+            pub fn floor(self) -> Self {
+                use crate::codegen::math::float::floor::Floor;
+                Floor::floor(self)
+            }
+
+            /// Smallest integer greater than or equal to each lane.
+            #[inline]
+            pub fn ceil(self) -> Self {
+                use crate::codegen::math::float::ceil::Ceil;
+                Ceil::ceil(self)
+            }
+
+            /// Nearest integer to each lane, rounding half-way cases away
+            /// from zero.
+            #[inline]
+            pub fn round(self) -> Self {
+                use crate::codegen::math::float::round::Round;
+                Round::round(self)
+            }
+
+            /// Integer part of each lane, rounding towards zero.
+            #[inline]
+            pub fn trunc(self) -> Self {
+                use crate::codegen::math::float::trunc::Trunc;
+                Trunc::trunc(self)
+            }
+
+            /// Nearest integer to each lane, rounding half-way cases to the
+            /// nearest even integer.
+            #[inline]
+            pub fn round_ties_even(self) -> Self {
+                use crate::codegen::math::float::round_ties_even::RoundTiesEven;
+                RoundTiesEven::round_ties_even(self)
+            }
+
+            /// Fractional part of each lane, `self - self.trunc()`.
+            #[inline]
+            pub fn fract(self) -> Self {
+                self - self.trunc()
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item_with_macros! {
+                pub mod [<$id _math_round>] {
+                    use super::*;
+
+                    const VALUES: [$elem_ty; 24] = [
+                        0., -0., 0.25, -0.25, 0.5, -0.5, 0.75, -0.75,
+                        1., -1., 1.5, -1.5, 2.5, -2.5, 3.49, -3.51,
+                        1e7 + 0.5, -1e7 - 0.5, 1e15, -1e15, 1e30, -1e30,
+                        $elem_ty::INFINITY, $elem_ty::NEG_INFINITY,
+                    ];
+
+                    fn round_ties_even_ref(x: $elem_ty) -> $elem_ty {
+                        let r = x.round();
+                        if (x - x.trunc()).abs() == 0.5 {
+                            2. * (x / 2.).round()
+                        } else {
+                            r
+                        }
+                    }
+
+                    macro_rules! test_func {
+                        ($func:ident, $scalar:expr) => {{
+                            let n = VALUES.len();
+                            for j in 0..n {
+                                let mut x = $id::splat(0.);
+                                let mut e = $id::splat(0.);
+                                for i in 0..$id::lanes() {
+                                    let v = VALUES[(i + j) % n];
+                                    x = x.replace(i, v);
+                                    e = e.replace(i, $scalar(v));
+                                }
+                                let r = x.$func();
+                                assert_eq!(r, e, "{}({:?})", stringify!($func), x);
+                                // the sign of zero results is preserved:
+                                for i in 0..$id::lanes() {
+                                    assert_eq!(
+                                        r.extract(i).is_sign_negative(),
+                                        e.extract(i).is_sign_negative()
+                                    );
+                                }
+                            }
+                            let nan = $id::splat($elem_ty::NAN);
+                            assert!(nan.$func().is_nan().all());
+                        }};
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn floor() {
+                        test_func!(floor, |v: $elem_ty| v.floor());
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ceil() {
+                        test_func!(ceil, |v: $elem_ty| v.ceil());
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn round() {
+                        test_func!(round, |v: $elem_ty| v.round());
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn trunc() {
+                        test_func!(trunc, |v: $elem_ty| v.trunc());
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn round_ties_even() {
+                        test_func!(round_ties_even, round_ties_even_ref);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn fract() {
+                        let x = $id::splat(2.5);
+                        assert_eq!(x.fract(), $id::splat(0.5));
+                        assert_eq!((-x).fract(), $id::splat(-0.5));
+                        assert_eq!($id::splat(3.).fract(), $id::splat(0.));
+                    }
+                }
+            }
+        }
+    };
+}
@@ -4,12 +4,24 @@ macro_rules! impl_math_float_sin {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Sine.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn sin(self) -> Self {
                 use crate::codegen::math::float::sin::Sin;
                 Sin::sin(self)
             }
 
+            /// Sine, with a larger error bound than [`sin`](#method.sin).
+            ///
+            /// The maximum error is 4 ULP.
+            #[inline]
+            pub fn sin_fast(self) -> Self {
+                use crate::codegen::math::float::sin_fast::SinFast;
+                SinFast::sin_fast(self)
+            }
+
             /// Sine of `self * PI`.
             #[inline]
             pub fn sin_pi(self) -> Self {
@@ -42,6 +54,53 @@ macro_rules! impl_math_float_sin {
                         assert_eq!(z, z.sin());
                         assert_eq!(o_r, ph.sin());
                         assert_eq!(z_r, p.sin());
+                        assert!((-z).sin().extract(0).is_sign_negative());
+                        assert!($id::splat(crate::$elem_ty::INFINITY).sin().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).sin().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            1e-20, -1e-20, 1e4, -1e4, 1e6, 1e10, -1e10,
+                            crate::$elem_ty::MIN_POSITIVE, crate::$elem_ty::MAX,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: sin, values, 2);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sin_fast() {
+                        let z = $id::splat(0 as $elem_ty);
+                        assert_eq!(z, z.sin_fast());
+                        assert!((-z).sin_fast().extract(0).is_sign_negative());
+                        assert!($id::splat(crate::$elem_ty::INFINITY).sin_fast().is_nan().all());
+                        assert!($id::splat(crate::$elem_ty::NAN).sin_fast().is_nan().all());
+
+                        const N: usize = 4096;
+                        let specials = [
+                            1e-20, -1e-20, 8192., -8192., 8193., 1e6, 1e10,
+                            crate::$elem_ty::MIN_POSITIVE, crate::$elem_ty::MAX,
+                        ];
+                        let values = (0..=N)
+                            .map(|k| -100. + 200. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: sin_fast => sin, values, 4);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sin_pi() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let h = $id::splat(0.5 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+
+                        assert_eq!(z, z.sin_pi());
+                        assert!((-z).sin_pi().extract(0).is_sign_negative());
+                        assert_eq!(o, h.sin_pi());
+
+                        let (s, c) = (-z).sin_cos_pi();
+                        assert!(s.extract(0).is_sign_negative());
+                        assert_eq!(o, c);
                     }
                 }
             }
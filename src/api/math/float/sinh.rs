@@ -0,0 +1,44 @@
+//! Implements vertical (lane-wise) floating-point `sinh`.
+
+macro_rules! impl_math_float_sinh {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Hyperbolic sine.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 4 ULP otherwise.
+            #[inline]
+            pub fn sinh(self) -> Self {
+                use crate::codegen::math::float::sinh::Sinh;
+                Sinh::sinh(self)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_sinh>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sinh() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(z, z.sinh());
+                        assert!((-z).sinh().extract(0).is_sign_negative());
+                        assert_eq!(inf, inf.sinh());
+                        assert_eq!(-inf, (-inf).sinh());
+                        assert!($id::splat(crate::$elem_ty::NAN).sinh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let max = crate::$elem_ty::MAX.ln() + 0.6;
+                        let specials = [1e-20, -1e-20, 0.999, 1., 1.001, max, -max, 1e3];
+                        let values = (0..=N)
+                            .map(|k| -20. + 40. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain(specials.iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: sinh, values, 4);
+                    }
+                }
+            }
+        }
+    };
+}
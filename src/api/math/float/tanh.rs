@@ -4,6 +4,9 @@ macro_rules! impl_math_float_tanh {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         impl $id {
             /// Tanh.
+            ///
+            /// The maximum error is 1 ULP with the `sleef-sys` backend and
+            /// 2 ULP otherwise.
             #[inline]
             pub fn tanh(self) -> Self {
                 use crate::codegen::math::float::tanh::Tanh;
@@ -21,6 +24,19 @@ macro_rules! impl_math_float_tanh {
                         let z = $id::splat(0 as $elem_ty);
 
                         assert_eq!(z, z.tanh());
+                        assert!((-z).tanh().extract(0).is_sign_negative());
+                        let o = $id::splat(1 as $elem_ty);
+                        let inf = $id::splat(crate::$elem_ty::INFINITY);
+                        assert_eq!(o, inf.tanh());
+                        assert_eq!(-o, (-inf).tanh());
+                        assert!($id::splat(crate::$elem_ty::NAN).tanh().is_nan().all());
+
+                        const N: usize = 4096;
+                        let values = (0..=N)
+                            .map(|k| -20. + 40. * (k as $elem_ty) / (N as $elem_ty))
+                            .chain((1..40).map(|k| (2 as $elem_ty).powi(-k)))
+                            .chain([1e-20, -1e-20, 100.].iter().cloned());
+                        test_ulp_unary!($id[$elem_ty]: tanh, values, 2);
                     }
                 }
             }
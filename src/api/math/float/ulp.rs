@@ -0,0 +1,181 @@
+//! Implements vertical (lane-wise) floating-point `next_up`, `next_down`,
+//! `next_after` and `ulp`.
+
+macro_rules! impl_math_float_ulp {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $iid:ident | $test_tt:tt) => {
+        impl $id {
+            /// Least number greater than each lane.
+            ///
+            /// Returns the smallest positive subnormal number for zero lanes
+            /// of either sign, `-MAX` for negative infinity, and positive
+            /// infinity and NaN lanes unchanged.
+            #[inline]
+            pub fn next_up(self) -> Self {
+                use crate::mem::transmute;
+                let bits: $iid = unsafe { transmute(self) };
+                let one = $iid::splat(1);
+                let next = bits.lt($iid::splat(0)).select(bits - one, bits + one);
+                let next = self.eq(Self::splat(0.)).select(one, next);
+                let next: Self = unsafe { transmute(next) };
+                (self.is_nan() | self.eq(Self::INFINITY)).select(self, next)
+            }
+
+            /// Greatest number less than each lane.
+            ///
+            /// Returns the smallest negative subnormal number for zero lanes
+            /// of either sign, `MAX` for positive infinity, and negative
+            /// infinity and NaN lanes unchanged.
+            #[inline]
+            pub fn next_down(self) -> Self {
+                -(-self).next_up()
+            }
+
+            /// Next number after each lane in the direction of `toward`.
+            ///
+            /// Returns [`next_up`](#method.next_up) in the lanes where
+            /// `toward` is greater than `self`, and
+            /// [`next_down`](#method.next_down) where it is less. Lanes that
+            /// are equal return `toward`, so that the sign of zeros is the
+            /// sign of `toward`, and lanes where either input is NaN return
+            /// NaN.
+            #[inline]
+            pub fn next_after(self, toward: Self) -> Self {
+                let r = toward.gt(self).select(self.next_up(), self.next_down());
+                let r = self.eq(toward).select(toward, r);
+                (self.is_nan() | toward.is_nan()).select(self + toward, r)
+            }
+
+            /// Unit in the last place of each lane.
+            ///
+            /// This is the distance between `|self|` and the next number of
+            /// greater magnitude, which would be infinite for `MAX`: the
+            /// result is `2^(e - MANTISSA_DIGITS + 1)` where `e` is the
+            /// exponent of the lane, and the smallest positive subnormal
+            /// number for subnormal and zero lanes. Infinite lanes return
+            /// positive infinity and NaN lanes return NaN.
+            #[inline]
+            pub fn ulp(self) -> Self {
+                use crate::mem::transmute;
+                const MANT: u32 = crate::$elem_ty::MANTISSA_DIGITS - 1;
+                let a = self.abs();
+                let bits: $iid = unsafe { transmute(a) };
+                let e = bits >> MANT;
+                let mant = $iid::splat(MANT as _);
+                let one = $iid::splat(1);
+                // A normal result has the exponent `e - MANT`, otherwise the
+                // result is the subnormal number with the bit `e - 1` set:
+                let normal = e.gt(mant);
+                let sub = one << (e - one).max($iid::splat(0)).min(mant);
+                let r = normal.select((e - mant) << MANT, sub);
+                let r: Self = unsafe { transmute(r) };
+                a.is_finite().select(r, a)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _math_ulp>] {
+                    use super::*;
+                    use crate::testing::utils::BitEq;
+
+                    fn assert_bits(r: $id, e: $elem_ty, msg: &str) {
+                        for i in 0..$id::lanes() {
+                            let r = r.extract(i);
+                            assert!(
+                                r.bit_eq(e),
+                                "{}: {:e} ({:#x}), expected {:e} ({:#x})",
+                                msg, r, r.to_bits(), e, e.to_bits()
+                            );
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn next_up_down() {
+                        use crate::$elem_ty::{EPSILON, INFINITY, MAX, MIN_POSITIVE, NAN};
+                        let tiny = $elem_ty::from_bits(1);
+                        let cases = [
+                            (0., tiny, -tiny),
+                            (-0., tiny, -tiny),
+                            (tiny, 2. * tiny, 0.),
+                            (-tiny, -0., -2. * tiny),
+                            (1., 1. + EPSILON, 1. - EPSILON / 2.),
+                            (-1., -1. + EPSILON / 2., -1. - EPSILON),
+                            (MIN_POSITIVE, MIN_POSITIVE + tiny, MIN_POSITIVE - tiny),
+                            (MAX, INFINITY, $elem_ty::from_bits(MAX.to_bits() - 1)),
+                            (-MAX, -$elem_ty::from_bits(MAX.to_bits() - 1), -INFINITY),
+                            (INFINITY, INFINITY, MAX),
+                            (-INFINITY, -MAX, -INFINITY),
+                            (NAN, NAN, NAN),
+                        ];
+                        for &(v, up, down) in cases.iter() {
+                            let x = $id::splat(v);
+                            assert_bits(x.next_up(), up, "next_up");
+                            assert_bits(x.next_down(), down, "next_down");
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn next_after() {
+                        use crate::$elem_ty::{EPSILON, INFINITY, MAX, NAN};
+                        let tiny = $elem_ty::from_bits(1);
+                        let cases = [
+                            (1., 2., 1. + EPSILON),
+                            (1., -1., 1. - EPSILON / 2.),
+                            (1., 1., 1.),
+                            (0., 1., tiny),
+                            (0., -1., -tiny),
+                            (-0., 1., tiny),
+                            (0., -0., -0.),
+                            (-0., 0., 0.),
+                            (tiny, 0., 0.),
+                            (-tiny, 0., -0.),
+                            (MAX, INFINITY, INFINITY),
+                            (INFINITY, 0., MAX),
+                            (-INFINITY, -INFINITY, -INFINITY),
+                            (NAN, 1., NAN),
+                            (1., NAN, NAN),
+                        ];
+                        for &(v, t, e) in cases.iter() {
+                            let r = $id::splat(v).next_after($id::splat(t));
+                            assert_bits(r, e, "next_after");
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn ulp() {
+                        use crate::$elem_ty::{EPSILON, INFINITY, MAX, MIN_POSITIVE, NAN};
+                        let tiny = $elem_ty::from_bits(1);
+                        let cases = [
+                            (0., tiny),
+                            (-0., tiny),
+                            (tiny, tiny),
+                            (MIN_POSITIVE / 2., tiny),
+                            (MIN_POSITIVE, tiny),
+                            (-MIN_POSITIVE * 4., 4. * tiny),
+                            (1., EPSILON),
+                            (-1.5, EPSILON),
+                            (3., 2. * EPSILON),
+                            (0.75, EPSILON / 2.),
+                            (MAX, MAX - $elem_ty::from_bits(MAX.to_bits() - 1)),
+                            (INFINITY, INFINITY),
+                            (-INFINITY, INFINITY),
+                            (NAN, NAN),
+                        ];
+                        for &(v, e) in cases.iter() {
+                            assert_bits($id::splat(v).ulp(), e, "ulp");
+                        }
+                        // The ulp is the distance to the next number of greater
+                        // magnitude:
+                        let mut x = 1e-3 as $elem_ty;
+                        while x < 1e3 {
+                            let v = $id::splat(x);
+                            assert_eq!(v.ulp(), v.next_up() - v);
+                            x *= 1.7;
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -21,12 +21,29 @@ mod scalar_shifts;
 
 #[macro_use]
 mod vector_rotates;
+#[macro_use]
+mod vector_funnel_shifts;
+
+#[macro_use]
+mod vector_saturating;
+#[macro_use]
+mod vector_overflowing;
+#[macro_use]
+mod vector_mul_high;
+#[macro_use]
+mod vector_avg;
 
 #[macro_use]
 mod vector_neg;
+#[macro_use]
+mod vector_int_sign;
 
 #[macro_use]
 mod vector_int_min_max;
+#[macro_use]
+mod vector_abs_diff;
 
 #[macro_use]
 mod vector_float_min_max;
+#[macro_use]
+mod vector_float_sign;
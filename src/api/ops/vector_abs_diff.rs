@@ -0,0 +1,61 @@
+//! Vertical (lane-wise) absolute difference of integer vectors.
+
+macro_rules! impl_ops_vector_abs_diff {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $uid:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise absolute difference.
+            ///
+            /// Computes `|self - x|` in each lane without overflowing. The
+            /// result is returned as an unsigned vector since it might not be
+            /// representable in the lane type of signed vectors.
+            #[inline]
+            pub fn abs_diff(self, x: Self) -> $uid {
+                // The difference between the maximum and the minimum always
+                // fits in the unsigned lane type, so the wrapping
+                // subtraction computes the right bits:
+                (self.max(x) - self.min(x)).cast()
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_abs_diff>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn abs_diff() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let f = $id::splat(4 as $elem_ty);
+                        let max = $id::splat($elem_ty::max_value());
+                        let min = $id::splat($elem_ty::min_value());
+
+                        let uz = $uid::splat(0);
+                        let uo = $uid::splat(1);
+                        let ut = $uid::splat(3);
+
+                        assert_eq!(z.abs_diff(z), uz);
+                        assert_eq!(z.abs_diff(o), uo);
+                        assert_eq!(o.abs_diff(z), uo);
+                        assert_eq!(f.abs_diff(o), ut);
+                        assert_eq!(o.abs_diff(f), ut);
+                        assert_eq!(max.abs_diff(min), !uz);
+                        assert_eq!(min.abs_diff(max), !uz);
+                        assert_eq!(max.abs_diff(max), uz);
+
+                        let mut a = o;
+                        let mut e = uz;
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                a = a.replace(i, 4);
+                                e = e.replace(i, 3);
+                            }
+                        }
+                        assert_eq!(a.abs_diff(o), e);
+                        assert_eq!(o.abs_diff(a), e);
+                    }
+                }
+            }
+        }
+    };
+}
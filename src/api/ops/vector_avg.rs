@@ -0,0 +1,96 @@
+//! Vertical (lane-wise) averages of 8-bit and 16-bit integer vectors.
+
+macro_rules! impl_ops_vector_avg {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise rounding average.
+            ///
+            /// Computes `(self + x + 1) >> 1` in each lane without
+            /// overflowing, that is, the average rounded towards positive
+            /// infinity.
+            #[inline]
+            pub fn avg(self, x: Self) -> Self {
+                use crate::codegen::avg::Avg;
+                Avg::avg(self, x)
+            }
+
+            /// Lane-wise truncating halving addition.
+            ///
+            /// Computes `(self + x) >> 1` in each lane without overflowing,
+            /// that is, the average rounded towards negative infinity.
+            #[inline]
+            pub fn halving_add(self, x: Self) -> Self {
+                use crate::codegen::avg::Avg;
+                Avg::halving_add(self, x)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_avg>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn avg() {
+                        let min = $elem_ty::min_value();
+                        let max = $elem_ty::max_value();
+                        let values = [
+                            min, min + 1, min / 2, 0, 1, 2, 3, 100,
+                            max / 2, max / 2 + 1, max - 1, max,
+                        ];
+                        let n = values.len();
+                        for j in 0..n {
+                            for k in 0..n {
+                                let mut a = $id::splat(0);
+                                let mut b = $id::splat(0);
+                                let mut r = $id::splat(0);
+                                let mut h = $id::splat(0);
+                                for i in 0..$id::lanes() {
+                                    let x = values[(i + j) % n];
+                                    let y = values[(i + k) % n];
+                                    a = a.replace(i, x);
+                                    b = b.replace(i, y);
+                                    let s = i32::from(x) + i32::from(y);
+                                    r = r.replace(i, ((s + 1) >> 1) as $elem_ty);
+                                    h = h.replace(i, (s >> 1) as $elem_ty);
+                                }
+                                assert_eq!(a.avg(b), r, "{:?}, {:?}", a, b);
+                                assert_eq!(b.avg(a), r, "{:?}, {:?}", a, b);
+                                assert_eq!(a.halving_add(b), h, "{:?}, {:?}", a, b);
+                                assert_eq!(b.halving_add(a), h, "{:?}, {:?}", a, b);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::*;
+
+impl_ops_vector_avg!([u8; 2]: u8x2 | test_v16);
+impl_ops_vector_avg!([u8; 4]: u8x4 | test_v32);
+impl_ops_vector_avg!([u8; 8]: u8x8 | test_v64);
+impl_ops_vector_avg!([u8; 16]: u8x16 | test_v128);
+impl_ops_vector_avg!([u8; 32]: u8x32 | test_v256);
+impl_ops_vector_avg!([u8; 64]: u8x64 | test_v512);
+
+impl_ops_vector_avg!([u16; 2]: u16x2 | test_v32);
+impl_ops_vector_avg!([u16; 4]: u16x4 | test_v64);
+impl_ops_vector_avg!([u16; 8]: u16x8 | test_v128);
+impl_ops_vector_avg!([u16; 16]: u16x16 | test_v256);
+impl_ops_vector_avg!([u16; 32]: u16x32 | test_v512);
+
+impl_ops_vector_avg!([i8; 2]: i8x2 | test_v16);
+impl_ops_vector_avg!([i8; 4]: i8x4 | test_v32);
+impl_ops_vector_avg!([i8; 8]: i8x8 | test_v64);
+impl_ops_vector_avg!([i8; 16]: i8x16 | test_v128);
+impl_ops_vector_avg!([i8; 32]: i8x32 | test_v256);
+impl_ops_vector_avg!([i8; 64]: i8x64 | test_v512);
+
+impl_ops_vector_avg!([i16; 2]: i16x2 | test_v32);
+impl_ops_vector_avg!([i16; 4]: i16x4 | test_v64);
+impl_ops_vector_avg!([i16; 8]: i16x8 | test_v128);
+impl_ops_vector_avg!([i16; 16]: i16x16 | test_v256);
+impl_ops_vector_avg!([i16; 32]: i16x32 | test_v512);
@@ -1,4 +1,5 @@
-//! Vertical (lane-wise) vector `min` and `max` for floating-point vectors.
+//! Vertical (lane-wise) vector `min`, `max`, `minimum`, `maximum` and
+//! `clamp` for floating-point vectors.
 
 macro_rules! impl_ops_vector_float_min_max {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
@@ -22,6 +23,77 @@ macro_rules! impl_ops_vector_float_min_max {
                 use crate::llvm::simd_fmax;
                 unsafe { Simd(simd_fmax(self.0, x.0)) }
             }
+
+            /// Lane-wise IEEE 754-2019 `minimum`.
+            ///
+            /// Unlike [`min`](#method.min), this returns NaN in the lanes
+            /// where either input is NaN, and `-0.0` is considered to be less
+            /// than `+0.0`.
+            #[inline]
+            pub fn minimum(self, x: Self) -> Self {
+                let r = self.lt(x).select(self, x);
+                // Equal lanes have the same bits, except for zeros of
+                // opposite signs, for which this sets the sign:
+                let z = Self::from_bits_u(self.to_bits() | x.to_bits());
+                let r = self.eq(x).select(z, r);
+                (self.is_nan() | x.is_nan()).select(self + x, r)
+            }
+
+            /// Lane-wise IEEE 754-2019 `maximum`.
+            ///
+            /// Unlike [`max`](#method.max), this returns NaN in the lanes
+            /// where either input is NaN, and `+0.0` is considered to be
+            /// greater than `-0.0`.
+            #[inline]
+            pub fn maximum(self, x: Self) -> Self {
+                let r = self.gt(x).select(self, x);
+                // Equal lanes have the same bits, except for zeros of
+                // opposite signs, for which this clears the sign:
+                let z = Self::from_bits_u(self.to_bits() & x.to_bits());
+                let r = self.eq(x).select(z, r);
+                (self.is_nan() | x.is_nan()).select(self + x, r)
+            }
+
+            /// Lane-wise IEEE 754-2019 `minimumNumber`.
+            ///
+            /// This returns the other input in the lanes where only one
+            /// input is NaN, and NaN where both are. `-0.0` is considered to
+            /// be less than `+0.0`.
+            #[inline]
+            pub fn minimum_number(self, x: Self) -> Self {
+                let r = self.minimum(x);
+                let r = self.is_nan().select(x, r);
+                x.is_nan().select(self, r)
+            }
+
+            /// Lane-wise IEEE 754-2019 `maximumNumber`.
+            ///
+            /// This returns the other input in the lanes where only one
+            /// input is NaN, and NaN where both are. `+0.0` is considered to
+            /// be greater than `-0.0`.
+            #[inline]
+            pub fn maximum_number(self, x: Self) -> Self {
+                let r = self.maximum(x);
+                let r = self.is_nan().select(x, r);
+                x.is_nan().select(self, r)
+            }
+
+            /// Restricts each lane to the interval `[lo, hi]`.
+            ///
+            /// Like the `clamp` method of the scalar floating-point types,
+            /// this returns NaN in the lanes that are NaN, and the lanes that
+            /// are within the interval unchanged, including zeros of either
+            /// sign.
+            ///
+            /// # Panics
+            ///
+            /// If `lo > hi` or either bound is NaN in any lane.
+            #[inline]
+            pub fn clamp(self, lo: Self, hi: Self) -> Self {
+                assert!(lo.le(hi).all(), "clamp requires lo <= hi in all lanes");
+                let r = self.lt(lo).select(lo, self);
+                r.gt(hi).select(hi, r)
+            }
         }
         test_if!{
             $test_tt:
@@ -70,5 +142,99 @@ macro_rules! impl_ops_vector_float_min_max {
                 }
             }
         }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_minimum_maximum>] {
+                    use super::*;
+                    use crate::testing::utils::BitEq;
+
+                    fn assert_bits(r: $id, e: $elem_ty, f: &str, a: $elem_ty, b: $elem_ty) {
+                        for i in 0..$id::lanes() {
+                            let r = r.extract(i);
+                            assert!(
+                                r.bit_eq(e),
+                                "{}({:e}, {:e}) = {:e} ({:#x}), expected {:e} ({:#x})",
+                                f, a, b, r, r.to_bits(), e, e.to_bits()
+                            );
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn minimum_maximum() {
+                        let n = crate::$elem_ty::NAN;
+                        let inf = crate::$elem_ty::INFINITY;
+                        // (a, b, minimum, maximum, minimum_number,
+                        // maximum_number):
+                        let cases = [
+                            (1., 2., 1., 2., 1., 2.),
+                            (-1., -inf, -inf, -1., -inf, -1.),
+                            (3., 3., 3., 3., 3., 3.),
+                            (0., -0., -0., 0., -0., 0.),
+                            (-0., 0., -0., 0., -0., 0.),
+                            (-0., -0., -0., -0., -0., -0.),
+                            (0., 0., 0., 0., 0., 0.),
+                            (n, 1., n, n, 1., 1.),
+                            (-0., n, n, n, -0., -0.),
+                            (n, inf, n, n, inf, inf),
+                            (n, n, n, n, n, n),
+                        ];
+                        for &(a, b, min, max, min_n, max_n) in cases.iter() {
+                            for &(a, b) in [(a, b), (b, a)].iter() {
+                                let (x, y) = ($id::splat(a), $id::splat(b));
+                                assert_bits(x.minimum(y), min, "minimum", a, b);
+                                assert_bits(x.maximum(y), max, "maximum", a, b);
+                                assert_bits(x.minimum_number(y), min_n, "minimum_number", a, b);
+                                assert_bits(x.maximum_number(y), max_n, "maximum_number", a, b);
+                            }
+                        }
+
+                        // Each lane is computed independently:
+                        let mut x = $id::splat(1.);
+                        let mut e = $id::splat(1.);
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                x = x.replace(i, n);
+                                e = e.replace(i, 0.);
+                            }
+                        }
+                        let r = x.minimum($id::splat(0.));
+                        for i in 0..$id::lanes() {
+                            assert_eq!(r.extract(i).is_nan(), i % 2 == 0);
+                        }
+                        assert_eq!(x.maximum_number($id::splat(0.)), e);
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn clamp() {
+                        let lo = $id::splat(-1.);
+                        let hi = $id::splat(2.);
+                        let cases = [
+                            (-3., -1.), (-1., -1.), (0.5, 0.5), (2., 2.), (5., 2.),
+                            (-0., -0.), (0., 0.),
+                            (crate::$elem_ty::INFINITY, 2.),
+                            (crate::$elem_ty::NEG_INFINITY, -1.),
+                            (crate::$elem_ty::NAN, crate::$elem_ty::NAN),
+                        ];
+                        for &(v, e) in cases.iter() {
+                            assert_bits($id::splat(v).clamp(lo, hi), e, "clamp", v, -1.);
+                        }
+                        assert_bits($id::splat(-0.).clamp($id::splat(0.), hi), -0., "clamp", -0., 0.);
+                        assert_bits($id::splat(3.).clamp(hi, hi), 2., "clamp", 3., 2.);
+                    }
+
+                    // FIXME: wasm-bindgen-test does not support #[should_panic]
+                    // #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    // #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    #[cfg(not(target_arch = "wasm32"))]
+                    #[test]
+                    #[should_panic]
+                    fn clamp_panic_nan_bound() {
+                        let _ = $id::splat(1.).clamp($id::splat(0.), $id::splat(crate::$elem_ty::NAN));
+                    }
+                }
+            }
+        }
     };
 }
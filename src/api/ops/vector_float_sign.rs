@@ -0,0 +1,137 @@
+//! Vertical (lane-wise) bit representation and sign of floating-point
+//! vectors.
+
+macro_rules! impl_ops_vector_float_sign {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident, $uid:ident
+     | $test_tt:tt) => {
+        impl $id {
+            /// Raw transmutation to an unsigned integer vector.
+            ///
+            /// Each lane of the result is the bit representation of the
+            /// corresponding lane of `self`, as with the `to_bits` method of
+            /// the scalar floating-point types.
+            #[inline]
+            pub fn to_bits(self) -> $uid {
+                unsafe { crate::mem::transmute(self) }
+            }
+
+            /// Raw transmutation from an unsigned integer vector.
+            ///
+            /// This is the inverse of [`to_bits`](#method.to_bits): it
+            /// never fails, and NaN payloads are preserved. It is not named
+            /// `from_bits` so that it does not shadow `FromBits::from_bits`,
+            /// which converts from any vector type of the same size.
+            #[inline]
+            pub fn from_bits_u(bits: $uid) -> Self {
+                unsafe { crate::mem::transmute(bits) }
+            }
+
+            /// Returns a mask whose lanes are `true` where the sign bit of
+            /// `self` is set, including for `-0.0` and NaNs with a negative
+            /// sign bit.
+            #[inline]
+            pub fn is_sign_negative(self) -> $mask_ty {
+                let s = Self::splat(-0.).to_bits();
+                (self.to_bits() & s).ne($uid::splat(0))
+            }
+
+            /// Returns a mask whose lanes are `true` where the sign bit of
+            /// `self` is clear, including for `+0.0` and NaNs with a positive
+            /// sign bit.
+            #[inline]
+            pub fn is_sign_positive(self) -> $mask_ty {
+                !self.is_sign_negative()
+            }
+
+            /// Lane-wise magnitude of `self` with the sign of `sign`.
+            ///
+            /// This only copies the sign bit, so it also applies to NaNs.
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                // Only the sign bit of `-0.0` is set:
+                let s = Self::splat(-0.).to_bits();
+                Self::from_bits_u((self.to_bits() & !s) | (sign.to_bits() & s))
+            }
+
+            /// Lane-wise sign.
+            ///
+            /// Returns `1.0` in the lanes whose sign bit is clear, including
+            /// `+0.0` and `+∞`, `-1.0` in the lanes whose sign bit is set,
+            /// including `-0.0` and `-∞`, and NaN in the lanes that are NaN.
+            #[inline]
+            pub fn signum(self) -> Self {
+                self.is_nan().select(self, Self::splat(1.).copysign(self))
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_float_sign>] {
+                    use super::*;
+
+                    fn values() -> [$elem_ty; 10] {
+                        [
+                            0., -0., 1., -2.5, 1e-3,
+                            $elem_ty::from_bits(1),
+                            crate::$elem_ty::MIN,
+                            crate::$elem_ty::INFINITY,
+                            crate::$elem_ty::NEG_INFINITY,
+                            crate::$elem_ty::NAN,
+                        ]
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn bits() {
+                        for &v in values().iter() {
+                            let x = $id::splat(v);
+                            assert_eq!(x.to_bits(), $uid::splat(v.to_bits()));
+                            assert_eq!($id::from_bits_u(x.to_bits()).to_bits(), x.to_bits());
+                            let nv = -v;
+                            assert_eq!((-x).to_bits(), $uid::splat(nv.to_bits()));
+                        }
+
+                        let mut b = $uid::splat(0);
+                        for i in 0..$id::lanes() {
+                            b = b.replace(i, (i as $elem_ty).to_bits());
+                        }
+                        let x = $id::from_bits_u(b);
+                        for i in 0..$id::lanes() {
+                            assert_eq!(x.extract(i), i as $elem_ty);
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sign() {
+                        let t = $mask_ty::splat(true);
+                        let f = $mask_ty::splat(false);
+                        for &v in values().iter() {
+                            for &v in [v, -v].iter() {
+                                let x = $id::splat(v);
+                                let neg = v.is_sign_negative();
+                                assert_eq!(x.is_sign_negative(), if neg { t } else { f });
+                                assert_eq!(x.is_sign_positive(), if neg { f } else { t });
+
+                                let s = x.signum();
+                                if v.is_nan() {
+                                    assert!(s.is_nan().all());
+                                } else {
+                                    assert_eq!(s, $id::splat(if neg { -1. } else { 1. }));
+                                }
+
+                                for &m in values().iter() {
+                                    for &m in [m, -m].iter() {
+                                        let r = $id::splat(m).copysign(x);
+                                        let s = (-0. as $elem_ty).to_bits();
+                                        let e = (m.to_bits() & !s) | (v.to_bits() & s);
+                                        assert_eq!(r.to_bits(), $uid::splat(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
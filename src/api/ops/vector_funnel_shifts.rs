@@ -0,0 +1,133 @@
+//! Vertical (lane-wise) funnel shifts and signed rotates.
+
+macro_rules! impl_ops_vector_funnel_shifts {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $iid:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise funnel shift left.
+            ///
+            /// Concatenates each lane of `self` (high half) with the
+            /// corresponding lane of `y` (low half), shifts the
+            /// double-width result to the left by `n` modulo the lane
+            /// width, and returns its high half.
+            ///
+            /// `x.funnel_shl(x, n)` is equivalent to `x.rotate_left(n)`.
+            #[inline]
+            pub fn funnel_shl(self, y: Self, n: Self) -> Self {
+                use crate::codegen::funnel_shift::FunnelShift;
+                FunnelShift::fshl(self, y, n)
+            }
+
+            /// Lane-wise funnel shift right.
+            ///
+            /// Concatenates each lane of `self` (high half) with the
+            /// corresponding lane of `y` (low half), shifts the
+            /// double-width result to the right by `n` modulo the lane
+            /// width, and returns its low half.
+            ///
+            /// `x.funnel_shr(x, n)` is equivalent to `x.rotate_right(n)`.
+            #[inline]
+            pub fn funnel_shr(self, y: Self, n: Self) -> Self {
+                use crate::codegen::funnel_shift::FunnelShift;
+                FunnelShift::fshr(self, y, n)
+            }
+
+            /// Rotates the bits of each lane by the signed amount in the
+            /// corresponding lane of `n`.
+            ///
+            /// Positive amounts rotate to the left and negative amounts
+            /// rotate to the right. The amount is taken modulo the lane
+            /// width.
+            #[inline]
+            pub fn rotate(self, n: $iid) -> Self {
+                // Rotating right by `k` is rotating left by `-k`, which in
+                // two's complement is the same modulo the lane width:
+                self.funnel_shl(self, n.cast())
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_funnel_shifts>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn funnel_shifts() {
+                        const BITS: u32 = mem::size_of::<$elem_ty>() as u32 * 8;
+
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let t = $id::splat(2 as $elem_ty);
+                        let a = !z;
+                        let h = o << (BITS - 1);
+                        let w = $id::splat(BITS as $elem_ty);
+                        let m = w - o;
+
+                        // funnel_shl
+                        assert_eq!(o.funnel_shl(a, z), o);
+                        assert_eq!(o.funnel_shl(a, w), o);
+                        assert_eq!(o.funnel_shl(z, o), t);
+                        assert_eq!(o.funnel_shl(a, o), t | o);
+                        assert_eq!(z.funnel_shl(h, o), o);
+                        assert_eq!(z.funnel_shl(a, m), a >> 1);
+                        assert_eq!(o.funnel_shl(z, m), h);
+
+                        // funnel_shr
+                        assert_eq!(o.funnel_shr(a, z), a);
+                        assert_eq!(o.funnel_shr(a, w), a);
+                        assert_eq!(z.funnel_shr(t, o), o);
+                        assert_eq!(o.funnel_shr(z, o), h);
+                        assert_eq!(a.funnel_shr(z, m), a << 1);
+                        assert_eq!(z.funnel_shr(h, m), o);
+
+                        // the lanes are independent
+                        let mut n = z;
+                        let mut e = o;
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                n = n.replace(i, 1);
+                                e = e.replace(i, 2);
+                            }
+                        }
+                        assert_eq!(o.funnel_shl(z, n), e);
+                        assert_eq!(e.funnel_shr(z, n), o);
+
+                        // rotates
+                        let s = (0x5a as $elem_ty) | (1 << (BITS - 1));
+                        let v = $id::splat(s);
+                        for i in 0..BITS {
+                            let n = $id::splat(i as $elem_ty);
+                            let l = $id::splat(s.rotate_left(i));
+                            let r = $id::splat(s.rotate_right(i));
+                            assert_eq!(v.funnel_shl(v, n), l);
+                            assert_eq!(v.funnel_shr(v, n), r);
+                        }
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn rotate() {
+                        const BITS: u32 = mem::size_of::<$elem_ty>() as u32 * 8;
+
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let h = o << (BITS - 1);
+                        let s = (0x5a as $elem_ty) | (1 << (BITS - 1));
+                        let v = $id::splat(s);
+
+                        assert_eq!(o.rotate($iid::splat(0)), o);
+                        assert_eq!(o.rotate($iid::splat(1)), o << 1);
+                        assert_eq!(o.rotate($iid::splat(-1)), h);
+                        assert_eq!(h.rotate($iid::splat(1)), o);
+                        assert_eq!(z.rotate($iid::splat(-1)), z);
+
+                        for i in 0..BITS {
+                            let p = $iid::splat(i as _);
+                            let n = -p;
+                            assert_eq!(v.rotate(p), $id::splat(s.rotate_left(i)));
+                            assert_eq!(v.rotate(n), $id::splat(s.rotate_right(i)));
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,120 @@
+//! Vertical (lane-wise) absolute value and sign of signed integer vectors.
+
+macro_rules! impl_ops_vector_int_sign {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident, $uid:ident
+     | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise absolute value.
+            ///
+            /// Like the arithmetic operators of this crate, this wraps on
+            /// overflow: lanes equal to `MIN` remain `MIN`. Use
+            /// [`unsigned_abs`](#method.unsigned_abs) to obtain the absolute
+            /// value of every lane without overflow.
+            #[inline]
+            pub fn abs(self) -> Self {
+                self.wrapping_abs()
+            }
+
+            /// Lane-wise wrapping absolute value.
+            ///
+            /// Lanes equal to `MIN` remain `MIN`.
+            #[inline]
+            pub fn wrapping_abs(self) -> Self {
+                self.is_negative().select(-self, self)
+            }
+
+            /// Lane-wise absolute value as an unsigned vector.
+            ///
+            /// This never overflows: lanes equal to `MIN` are mapped to
+            /// `MAX + 1`.
+            #[inline]
+            pub fn unsigned_abs(self) -> $uid {
+                self.wrapping_abs().cast()
+            }
+
+            /// Lane-wise sign.
+            ///
+            /// Returns `0` in the lanes that are zero, `1` in the lanes that
+            /// are positive, and `-1` in the lanes that are negative.
+            #[inline]
+            pub fn signum(self) -> Self {
+                let z = Self::splat(0);
+                let o = Self::splat(1);
+                self.is_positive().select(o, z) - self.is_negative().select(o, z)
+            }
+
+            /// Returns a mask whose lanes are `true` where `self` is strictly
+            /// positive.
+            #[inline]
+            pub fn is_positive(self) -> $mask_ty {
+                self.gt(Self::splat(0))
+            }
+
+            /// Returns a mask whose lanes are `true` where `self` is strictly
+            /// negative.
+            #[inline]
+            pub fn is_negative(self) -> $mask_ty {
+                self.lt(Self::splat(0))
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_int_sign>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sign() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let f = $id::splat(4 as $elem_ty);
+                        let no = $id::splat(-1 as $elem_ty);
+                        let nf = $id::splat(-4 as $elem_ty);
+                        let max = $id::splat($elem_ty::max_value());
+                        let min = $id::splat($elem_ty::min_value());
+
+                        // abs
+                        assert_eq!(z.abs(), z);
+                        assert_eq!(f.abs(), f);
+                        assert_eq!(nf.abs(), f);
+                        assert_eq!(max.abs(), max);
+                        assert_eq!((-max).abs(), max);
+                        assert_eq!(min.abs(), min);
+                        assert_eq!(min.wrapping_abs(), min);
+                        assert_eq!(nf.wrapping_abs(), f);
+
+                        // unsigned_abs
+                        assert_eq!(nf.unsigned_abs(), $uid::splat(4));
+                        assert_eq!(f.unsigned_abs(), $uid::splat(4));
+                        assert_eq!(
+                            min.unsigned_abs(),
+                            $uid::splat(1) << (mem::size_of::<$elem_ty>() as u32 * 8 - 1)
+                        );
+
+                        // signum
+                        assert_eq!(z.signum(), z);
+                        assert_eq!(f.signum(), o);
+                        assert_eq!(max.signum(), o);
+                        assert_eq!(nf.signum(), no);
+                        assert_eq!(min.signum(), no);
+
+                        // is_positive / is_negative
+                        let mut v = f;
+                        let mut e = $mask_ty::splat(false);
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                v = v.replace(i, -4);
+                                e = e.replace(i, true);
+                            }
+                        }
+                        assert_eq!(v.is_negative(), e);
+                        assert_eq!(v.is_positive(), !e);
+                        assert_eq!(v.abs(), f);
+                        assert!(z.is_negative().none());
+                        assert!(z.is_positive().none());
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,158 @@
+//! Vertical (lane-wise) high-half and widening integer multiplication.
+
+macro_rules! impl_ops_vector_mul_high {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise high-half multiplication.
+            ///
+            /// Returns the upper half of the double-width product
+            /// `self * x` in each lane, like `pmulhw`/`pmulhuw` on x86 or
+            /// `smulh`/`umulh` on AArch64.
+            #[inline]
+            pub fn mul_high(self, x: Self) -> Self {
+                use crate::codegen::mul_high::MulHigh;
+                MulHigh::mul_high(self, x)
+            }
+
+            /// Lane-wise widening multiplication.
+            ///
+            /// Returns the `(lo, hi)` halves of the double-width product
+            /// `self * x` in each lane, where `lo` is the wrapping product
+            /// and `hi` is [`mul_high`](#method.mul_high).
+            #[inline]
+            pub fn widening_mul(self, x: Self) -> (Self, Self) {
+                (self * x, self.mul_high(x))
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_mul_high>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn mul_high() {
+                        const BITS: u32 = mem::size_of::<$elem_ty>() as u32 * 8;
+                        let signed = $elem_ty::min_value() != 0;
+
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let f = $id::splat(4 as $elem_ty);
+                        let q = $id::splat(1 << (BITS - 2));
+                        let max = $id::splat($elem_ty::max_value());
+                        let min = $id::splat($elem_ty::min_value());
+
+                        assert_eq!(z.mul_high(max), z);
+                        assert_eq!(f.mul_high(o), z);
+                        assert_eq!(q.mul_high(f), o);
+                        assert_eq!(f.mul_high(q), o);
+                        if signed {
+                            assert_eq!(max.mul_high(max), max >> 1);
+                            assert_eq!(min.mul_high(min), (max >> 1) + o);
+                            assert_eq!(min.mul_high(o), !z);
+                            assert_eq!(max.mul_high(min), !(max >> 1));
+                        } else {
+                            assert_eq!(max.mul_high(max), max - o);
+                            assert_eq!(max.mul_high(o), z);
+                            assert_eq!(max.mul_high(f), f - o);
+                        }
+
+                        // the lanes are independent
+                        let mut a = f;
+                        let mut e = z;
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                a = a.replace(i, 1 << (BITS - 2));
+                                e = e.replace(i, 1);
+                            }
+                        }
+                        assert_eq!(a.mul_high(f), e);
+                        assert_eq!(a.widening_mul(f), (a * f, e));
+                        assert_eq!(q.widening_mul(f), (z, o));
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_ops_vector_mul_wide {
+    ($id:ident, $wide_id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise widening multiplication into a vector with lanes
+            /// twice as wide.
+            ///
+            /// Returns the exact product `self * x` of each lane.
+            #[inline]
+            pub fn mul_wide(self, x: Self) -> $wide_id {
+                let a: $wide_id = self.cast();
+                let b: $wide_id = x.cast();
+                a * b
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_mul_wide>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn mul_wide() {
+                        const BITS: u32 =
+                            (mem::size_of::<$id>() * 8 / $id::lanes()) as u32;
+
+                        let mut a = $id::splat(3);
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                a = a.replace(i, !0);
+                            }
+                        }
+                        let b = a - $id::splat(1);
+                        let a_w: $wide_id = a.cast();
+                        let b_w: $wide_id = b.cast();
+
+                        let p = a.mul_wide(b);
+                        assert_eq!(p, a_w * b_w);
+                        let (lo, hi) = a.widening_mul(b);
+                        assert_eq!(lo, p.cast());
+                        assert_eq!(hi, (p >> BITS).cast());
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::*;
+
+impl_ops_vector_mul_wide!(i8x2, i16x2 | test_v16);
+impl_ops_vector_mul_wide!(u8x2, u16x2 | test_v16);
+impl_ops_vector_mul_wide!(i8x4, i16x4 | test_v32);
+impl_ops_vector_mul_wide!(u8x4, u16x4 | test_v32);
+impl_ops_vector_mul_wide!(i8x8, i16x8 | test_v64);
+impl_ops_vector_mul_wide!(u8x8, u16x8 | test_v64);
+impl_ops_vector_mul_wide!(i8x16, i16x16 | test_v128);
+impl_ops_vector_mul_wide!(u8x16, u16x16 | test_v128);
+impl_ops_vector_mul_wide!(i8x32, i16x32 | test_v256);
+impl_ops_vector_mul_wide!(u8x32, u16x32 | test_v256);
+
+impl_ops_vector_mul_wide!(i16x2, i32x2 | test_v32);
+impl_ops_vector_mul_wide!(u16x2, u32x2 | test_v32);
+impl_ops_vector_mul_wide!(i16x4, i32x4 | test_v64);
+impl_ops_vector_mul_wide!(u16x4, u32x4 | test_v64);
+impl_ops_vector_mul_wide!(i16x8, i32x8 | test_v128);
+impl_ops_vector_mul_wide!(u16x8, u32x8 | test_v128);
+impl_ops_vector_mul_wide!(i16x16, i32x16 | test_v256);
+impl_ops_vector_mul_wide!(u16x16, u32x16 | test_v256);
+
+impl_ops_vector_mul_wide!(i32x2, i64x2 | test_v64);
+impl_ops_vector_mul_wide!(u32x2, u64x2 | test_v64);
+impl_ops_vector_mul_wide!(i32x4, i64x4 | test_v128);
+impl_ops_vector_mul_wide!(u32x4, u64x4 | test_v128);
+impl_ops_vector_mul_wide!(i32x8, i64x8 | test_v256);
+impl_ops_vector_mul_wide!(u32x8, u64x8 | test_v256);
+
+impl_ops_vector_mul_wide!(i64x2, i128x2 | test_v128);
+impl_ops_vector_mul_wide!(u64x2, u128x2 | test_v128);
+impl_ops_vector_mul_wide!(i64x4, i128x4 | test_v256);
+impl_ops_vector_mul_wide!(u64x4, u128x4 | test_v256);
@@ -0,0 +1,129 @@
+//! Vertical (lane-wise) overflowing and checked integer arithmetic.
+
+macro_rules! impl_ops_vector_overflowing {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident, $mask_ty:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise overflowing addition.
+            ///
+            /// Returns the wrapping sum `self + x` together with a mask
+            /// whose lanes are `true` where the addition overflowed.
+            #[inline]
+            pub fn overflowing_add(self, x: Self) -> (Self, $mask_ty) {
+                let r = self + x;
+                // The wrapping and the saturating results only differ in
+                // the lanes that overflowed:
+                (r, r.ne(self.saturating_add(x)))
+            }
+
+            /// Lane-wise overflowing subtraction.
+            ///
+            /// Returns the wrapping difference `self - x` together with a
+            /// mask whose lanes are `true` where the subtraction overflowed.
+            #[inline]
+            pub fn overflowing_sub(self, x: Self) -> (Self, $mask_ty) {
+                let r = self - x;
+                (r, r.ne(self.saturating_sub(x)))
+            }
+
+            /// Lane-wise overflowing multiplication.
+            ///
+            /// Returns the wrapping product `self * x` together with a mask
+            /// whose lanes are `true` where the multiplication overflowed.
+            #[inline]
+            pub fn overflowing_mul(self, x: Self) -> (Self, $mask_ty) {
+                let mut r = self;
+                let mut m = $mask_ty::splat(false);
+                for i in 0..$id::lanes() {
+                    let (v, o) = self.extract(i).overflowing_mul(x.extract(i));
+                    r = r.replace(i, v);
+                    m = m.replace(i, o);
+                }
+                (r, m)
+            }
+
+            /// Lane-wise checked addition.
+            ///
+            /// Returns `None` if the addition overflows in any lane.
+            #[inline]
+            pub fn checked_add(self, x: Self) -> Option<Self> {
+                let (r, o) = self.overflowing_add(x);
+                if o.any() { None } else { Some(r) }
+            }
+
+            /// Lane-wise checked subtraction.
+            ///
+            /// Returns `None` if the subtraction overflows in any lane.
+            #[inline]
+            pub fn checked_sub(self, x: Self) -> Option<Self> {
+                let (r, o) = self.overflowing_sub(x);
+                if o.any() { None } else { Some(r) }
+            }
+
+            /// Lane-wise checked multiplication.
+            ///
+            /// Returns `None` if the multiplication overflows in any lane.
+            #[inline]
+            pub fn checked_mul(self, x: Self) -> Option<Self> {
+                let (r, o) = self.overflowing_mul(x);
+                if o.any() { None } else { Some(r) }
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_overflowing>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn overflowing() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let t = $id::splat(2 as $elem_ty);
+                        let f = $id::splat(4 as $elem_ty);
+                        let max = $id::splat($elem_ty::max_value());
+                        let min = $id::splat($elem_ty::min_value());
+                        let none = $mask_ty::splat(false);
+                        let all = $mask_ty::splat(true);
+
+                        // overflowing_add
+                        assert_eq!(t.overflowing_add(t), (f, none));
+                        assert_eq!(max.overflowing_add(z), (max, none));
+                        assert_eq!(max.overflowing_add(o), (min, all));
+
+                        // overflowing_sub
+                        assert_eq!(f.overflowing_sub(t), (t, none));
+                        assert_eq!(min.overflowing_sub(z), (min, none));
+                        assert_eq!(min.overflowing_sub(o), (max, all));
+
+                        // overflowing_mul
+                        assert_eq!(t.overflowing_mul(t), (f, none));
+                        assert_eq!(max.overflowing_mul(o), (max, none));
+                        assert_eq!(max.overflowing_mul(t), (max * t, all));
+
+                        // the mask reports the lanes that overflowed
+                        let mut v = o;
+                        let mut e = t;
+                        let mut m = none;
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                v = v.replace(i, $elem_ty::max_value());
+                                e = e.replace(i, $elem_ty::min_value());
+                                m = m.replace(i, true);
+                            }
+                        }
+                        assert_eq!(v.overflowing_add(o), (e, m));
+
+                        // checked
+                        assert_eq!(t.checked_add(t), Some(f));
+                        assert_eq!(max.checked_add(o), None);
+                        assert_eq!(v.checked_add(o), None);
+                        assert_eq!(f.checked_sub(t), Some(t));
+                        assert_eq!(min.checked_sub(o), None);
+                        assert_eq!(t.checked_mul(t), Some(f));
+                        assert_eq!(max.checked_mul(t), None);
+                    }
+                }
+            }
+        }
+    };
+}
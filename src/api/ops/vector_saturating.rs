@@ -0,0 +1,122 @@
+//! Vertical (lane-wise) saturating integer arithmetic.
+
+macro_rules! impl_ops_vector_saturating {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Lane-wise saturating addition.
+            ///
+            /// Computes `self + x` in each lane, saturating at the numeric
+            /// bounds of the lane type instead of overflowing.
+            #[inline]
+            pub fn saturating_add(self, x: Self) -> Self {
+                use crate::llvm::simd_saturating_add;
+                unsafe { Simd(simd_saturating_add(self.0, x.0)) }
+            }
+
+            /// Lane-wise saturating subtraction.
+            ///
+            /// Computes `self - x` in each lane, saturating at the numeric
+            /// bounds of the lane type instead of overflowing.
+            #[inline]
+            pub fn saturating_sub(self, x: Self) -> Self {
+                use crate::llvm::simd_saturating_sub;
+                unsafe { Simd(simd_saturating_sub(self.0, x.0)) }
+            }
+
+            /// Lane-wise saturating multiplication.
+            ///
+            /// Computes `self * x` in each lane, saturating at the numeric
+            /// bounds of the lane type instead of overflowing.
+            #[inline]
+            pub fn saturating_mul(self, x: Self) -> Self {
+                // No target supported by this crate provides a saturating
+                // integer multiply, so this is computed lane-wise.
+                let mut r = self;
+                for i in 0..$id::lanes() {
+                    r = r.replace(
+                        i, self.extract(i).saturating_mul(x.extract(i))
+                    );
+                }
+                r
+            }
+
+            /// Saturating addition of the scalar `x` to every lane of `self`.
+            #[inline]
+            pub fn saturating_add_scalar(self, x: $elem_ty) -> Self {
+                self.saturating_add($id::splat(x))
+            }
+
+            /// Saturating subtraction of the scalar `x` from every lane of
+            /// `self`.
+            #[inline]
+            pub fn saturating_sub_scalar(self, x: $elem_ty) -> Self {
+                self.saturating_sub($id::splat(x))
+            }
+
+            /// Saturating multiplication of every lane of `self` by the
+            /// scalar `x`.
+            #[inline]
+            pub fn saturating_mul_scalar(self, x: $elem_ty) -> Self {
+                self.saturating_mul($id::splat(x))
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _ops_vector_saturating>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn saturating() {
+                        let z = $id::splat(0 as $elem_ty);
+                        let o = $id::splat(1 as $elem_ty);
+                        let t = $id::splat(2 as $elem_ty);
+                        let f = $id::splat(4 as $elem_ty);
+                        let max = $id::splat($elem_ty::max_value());
+                        let min = $id::splat($elem_ty::min_value());
+
+                        // saturating_add
+                        assert_eq!(z.saturating_add(z), z);
+                        assert_eq!(t.saturating_add(t), f);
+                        assert_eq!(max.saturating_add(z), max);
+                        assert_eq!(max.saturating_add(o), max);
+                        assert_eq!(max.saturating_add(max), max);
+                        assert_eq!(min.saturating_add(min), min);
+                        assert_eq!(o.saturating_add_scalar(1), t);
+                        assert_eq!(max.saturating_add_scalar(2), max);
+
+                        // saturating_sub
+                        assert_eq!(f.saturating_sub(t), t);
+                        assert_eq!(min.saturating_sub(z), min);
+                        assert_eq!(min.saturating_sub(o), min);
+                        assert_eq!(min.saturating_sub(max), min);
+                        assert_eq!(max.saturating_sub(max), z);
+                        assert_eq!(t.saturating_sub_scalar(1), o);
+                        assert_eq!(min.saturating_sub_scalar(2), min);
+
+                        // saturating_mul
+                        assert_eq!(t.saturating_mul(t), f);
+                        assert_eq!(max.saturating_mul(z), z);
+                        assert_eq!(max.saturating_mul(o), max);
+                        assert_eq!(max.saturating_mul(t), max);
+                        assert_eq!(min.saturating_mul(t), min);
+                        assert_eq!(t.saturating_mul_scalar(2), f);
+                        assert_eq!(max.saturating_mul_scalar(4), max);
+
+                        // lanes saturate independently
+                        let mut v = o;
+                        let mut e = t;
+                        for i in 0..$id::lanes() {
+                            if i % 2 == 0 {
+                                v = v.replace(i, $elem_ty::max_value());
+                                e = e.replace(i, $elem_ty::max_value());
+                            }
+                        }
+                        assert_eq!(v.saturating_add(o), e);
+                        assert_eq!(v.saturating_mul(t), e);
+                    }
+                }
+            }
+        }
+    };
+}
@@ -5,6 +5,8 @@ mod float_arithmetic;
 #[macro_use]
 mod integer_arithmetic;
 #[macro_use]
+mod sad;
+#[macro_use]
 mod bitwise;
 #[macro_use]
 mod mask;
@@ -13,14 +13,37 @@ macro_rules! impl_reduction_float_arithmetic {
             /// If one of the vector element is `NaN` the reduction returns
             /// `NaN`. The resulting `NaN` is not required to be equal to any
             /// of the `NaN`s in the vector.
+            ///
+            /// With the `deterministic_math` feature the reduction is
+            /// performed in exactly this order on all targets, so that the
+            /// result is bit-identical everywhere.
             #[inline]
             pub fn sum(self) -> $elem_ty {
-                #[cfg(not(target_arch = "aarch64"))]
+                #[cfg(feature = "deterministic_math")]
+                {
+                    let mut x = [0 as $elem_ty; $elem_count];
+                    self.write_to_slice_unaligned(&mut x);
+                    let mut n = $elem_count;
+                    while n > 1 {
+                        n /= 2;
+                        for i in 0..n {
+                            x[i] = x[2 * i] + x[2 * i + 1];
+                        }
+                    }
+                    x[0]
+                }
+                #[cfg(all(
+                    not(target_arch = "aarch64"),
+                    not(feature = "deterministic_math")
+                ))]
                 {
                     use crate::llvm::simd_reduce_add_ordered;
                     unsafe { simd_reduce_add_ordered(self.0, 0 as $elem_ty) }
                 }
-                #[cfg(target_arch = "aarch64")]
+                #[cfg(all(
+                    target_arch = "aarch64",
+                    not(feature = "deterministic_math")
+                ))]
                 {
                     // FIXME: broken on AArch64
                     // https://github.com/rust-lang-nursery/packed_simd/issues/15
@@ -42,14 +65,37 @@ macro_rules! impl_reduction_float_arithmetic {
             /// If one of the vector element is `NaN` the reduction returns
             /// `NaN`. The resulting `NaN` is not required to be equal to any
             /// of the `NaN`s in the vector.
+            ///
+            /// With the `deterministic_math` feature the reduction is
+            /// performed in exactly this order on all targets, so that the
+            /// result is bit-identical everywhere.
             #[inline]
             pub fn product(self) -> $elem_ty {
-                #[cfg(not(target_arch = "aarch64"))]
+                #[cfg(feature = "deterministic_math")]
+                {
+                    let mut x = [1 as $elem_ty; $elem_count];
+                    self.write_to_slice_unaligned(&mut x);
+                    let mut n = $elem_count;
+                    while n > 1 {
+                        n /= 2;
+                        for i in 0..n {
+                            x[i] = x[2 * i] * x[2 * i + 1];
+                        }
+                    }
+                    x[0]
+                }
+                #[cfg(all(
+                    not(target_arch = "aarch64"),
+                    not(feature = "deterministic_math")
+                ))]
                 {
                     use crate::llvm::simd_reduce_mul_ordered;
                     unsafe { simd_reduce_mul_ordered(self.0, 1 as $elem_ty) }
                 }
-                #[cfg(target_arch = "aarch64")]
+                #[cfg(all(
+                    target_arch = "aarch64",
+                    not(feature = "deterministic_math")
+                ))]
                 {
                     // FIXME: broken on AArch64
                     // https://github.com/rust-lang-nursery/packed_simd/issues/15
@@ -236,6 +282,9 @@ macro_rules! impl_reduction_float_arithmetic {
                         // tolerate 1 ULP difference:
                         let red_bits = simd_reduction.to_bits();
                         let tree_bits = tree_reduction.to_bits();
+                        if cfg!(feature = "deterministic_math") {
+                            assert_eq!(red_bits, tree_bits);
+                        }
                         assert!(
                             if red_bits > tree_bits {
                                 red_bits - tree_bits
@@ -292,6 +341,9 @@ tree_reduction: {} | scalar_reduction: {}",
                         let ulp_limit = $id::lanes() / 2;
                         let red_bits = simd_reduction.to_bits();
                         let tree_bits = tree_reduction.to_bits();
+                        if cfg!(feature = "deterministic_math") {
+                            assert_eq!(red_bits, tree_bits);
+                        }
                         assert!(
                             if red_bits > tree_bits {
                                 red_bits - tree_bits
@@ -121,6 +121,161 @@ macro_rules! impl_reduction_min_max {
     };
 }
 
+macro_rules! impl_reduction_float_minimum_maximum {
+    ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
+        impl $id {
+            /// Smallest vector element value, as computed by
+            /// [`minimum`](#method.minimum).
+            ///
+            /// Returns NaN if any element is NaN, and considers `-0.0` to be
+            /// less than `+0.0`.
+            #[inline]
+            pub fn minimum_element(self) -> $elem_ty {
+                let mut x = self.extract(0);
+                for i in 1..$id::lanes() {
+                    let y = self.extract(i);
+                    x = if x.is_nan() || y.is_nan() {
+                        x + y
+                    } else if x < y {
+                        x
+                    } else if y < x {
+                        y
+                    } else {
+                        // Equal, but possibly zeros of different signs:
+                        $elem_ty::from_bits(x.to_bits() | y.to_bits())
+                    };
+                }
+                x
+            }
+
+            /// Largest vector element value, as computed by
+            /// [`maximum`](#method.maximum).
+            ///
+            /// Returns NaN if any element is NaN, and considers `+0.0` to be
+            /// greater than `-0.0`.
+            #[inline]
+            pub fn maximum_element(self) -> $elem_ty {
+                let mut x = self.extract(0);
+                for i in 1..$id::lanes() {
+                    let y = self.extract(i);
+                    x = if x.is_nan() || y.is_nan() {
+                        x + y
+                    } else if x > y {
+                        x
+                    } else if y > x {
+                        y
+                    } else {
+                        // Equal, but possibly zeros of different signs:
+                        $elem_ty::from_bits(x.to_bits() & y.to_bits())
+                    };
+                }
+                x
+            }
+
+            /// Smallest vector element value, as computed by
+            /// [`minimum_number`](#method.minimum_number).
+            ///
+            /// NaN elements are ignored: returns NaN only if all elements
+            /// are NaN. `-0.0` is considered to be less than `+0.0`.
+            #[inline]
+            pub fn minimum_number_element(self) -> $elem_ty {
+                let mut x = self.extract(0);
+                for i in 1..$id::lanes() {
+                    let y = self.extract(i);
+                    x = if y.is_nan() {
+                        x
+                    } else if x.is_nan() || y < x {
+                        y
+                    } else if x < y {
+                        x
+                    } else {
+                        $elem_ty::from_bits(x.to_bits() | y.to_bits())
+                    };
+                }
+                x
+            }
+
+            /// Largest vector element value, as computed by
+            /// [`maximum_number`](#method.maximum_number).
+            ///
+            /// NaN elements are ignored: returns NaN only if all elements
+            /// are NaN. `+0.0` is considered to be greater than `-0.0`.
+            #[inline]
+            pub fn maximum_number_element(self) -> $elem_ty {
+                let mut x = self.extract(0);
+                for i in 1..$id::lanes() {
+                    let y = self.extract(i);
+                    x = if y.is_nan() {
+                        x
+                    } else if x.is_nan() || y > x {
+                        y
+                    } else if x > y {
+                        x
+                    } else {
+                        $elem_ty::from_bits(x.to_bits() & y.to_bits())
+                    };
+                }
+                x
+            }
+        }
+
+        test_if! {
+            $test_tt:
+            paste::item! {
+                #[allow(clippy::float_cmp)]
+                pub mod [<$id _reduction_minimum_maximum>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)]
+                    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn minimum_maximum_element() {
+                        let n = crate::$elem_ty::NAN;
+                        let v0 = $id::splat(1.);
+                        for i in 0..$id::lanes() {
+                            let v = v0.replace(i, -3.);
+                            assert_eq!(v.minimum_element().to_bits(), (-3. as $elem_ty).to_bits());
+                            assert_eq!(v.minimum_number_element().to_bits(), (-3. as $elem_ty).to_bits());
+                            let v = v0.replace(i, 3.);
+                            assert_eq!(v.maximum_element().to_bits(), (3. as $elem_ty).to_bits());
+                            assert_eq!(v.maximum_number_element().to_bits(), (3. as $elem_ty).to_bits());
+
+                            // A single NaN poisons `minimum_element` and
+                            // `maximum_element`, wherever it is:
+                            let v = v0.replace(i, n);
+                            assert!(v.minimum_element().is_nan());
+                            assert!(v.maximum_element().is_nan());
+                            if $id::lanes() > 1 {
+                                assert_eq!(v.minimum_number_element(), 1.);
+                                assert_eq!(v.maximum_number_element(), 1.);
+                            } else {
+                                assert!(v.minimum_number_element().is_nan());
+                                assert!(v.maximum_number_element().is_nan());
+                            }
+
+                            // Signed zeros:
+                            let z = $id::splat(0.).replace(i, -0.);
+                            let neg_zero = (-0. as $elem_ty).to_bits();
+                            assert_eq!(z.minimum_element().to_bits(), neg_zero);
+                            assert_eq!(z.minimum_number_element().to_bits(), neg_zero);
+                            let z = $id::splat(-0.).replace(i, 0.);
+                            assert_eq!(z.maximum_element().to_bits(), 0);
+                            assert_eq!(z.maximum_number_element().to_bits(), 0);
+                            if $id::lanes() > 1 {
+                                assert_eq!(z.minimum_element().to_bits(), neg_zero);
+                            }
+                        }
+
+                        let v = $id::splat(n);
+                        assert!(v.minimum_element().is_nan());
+                        assert!(v.maximum_element().is_nan());
+                        assert!(v.minimum_number_element().is_nan());
+                        assert!(v.maximum_number_element().is_nan());
+                    }
+                }
+            }
+        }
+    };
+}
+
 macro_rules! test_reduction_float_min_max {
     ([$elem_ty:ident; $elem_count:expr]: $id:ident | $test_tt:tt) => {
         test_if! {
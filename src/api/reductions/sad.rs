@@ -0,0 +1,63 @@
+//! Implements the sum of absolute differences reduction.
+
+macro_rules! impl_reduction_sad {
+    ($id:ident, $out:ident | $test_tt:tt) => {
+        impl $id {
+            /// Sum of absolute differences.
+            ///
+            /// Computes the absolute differences of the lanes of `self` and
+            /// `x`, and sums each group of 8 consecutive differences into a
+            /// lane of the result, like `psadbw` on x86.
+            #[inline]
+            pub fn sad(self, x: Self) -> $out {
+                use crate::codegen::reductions::sad::Sad;
+                Sad::sad(self, x)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _reduction_sad>] {
+                    use super::*;
+
+                    fn sad_scalar(a: $id, b: $id) -> $out {
+                        let mut r = $out::splat(0);
+                        for i in 0..$id::lanes() {
+                            let (x, y) = (a.extract(i), b.extract(i));
+                            let d = if x > y { x - y } else { y - x };
+                            r = r.replace(i / 8, r.extract(i / 8) + u64::from(d));
+                        }
+                        r
+                    }
+
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn sad() {
+                        let z = $id::splat(0);
+                        let m = $id::splat(255);
+                        assert_eq!(z.sad(z), $out::splat(0));
+                        assert_eq!(z.sad(m), $out::splat(8 * 255));
+                        assert_eq!(m.sad(z), $out::splat(8 * 255));
+
+                        let mut a = z;
+                        let mut b = z;
+                        for i in 0..$id::lanes() {
+                            a = a.replace(i, (i * 37 % 256) as u8);
+                            b = b.replace(i, (255 - i * 11 % 256) as u8);
+                        }
+                        assert_eq!(a.sad(b), sad_scalar(a, b));
+                        assert_eq!(b.sad(a), sad_scalar(a, b));
+                        assert_eq!(a.sad(m), sad_scalar(a, m));
+                        assert_eq!(z.sad(b), sad_scalar(z, b));
+                    }
+                }
+            }
+        }
+    };
+}
+
+use crate::*;
+
+impl_reduction_sad!(u8x16, u64x2 | test_v128);
+impl_reduction_sad!(u8x32, u64x4 | test_v256);
+impl_reduction_sad!(u8x64, u64x8 | test_v512);
@@ -0,0 +1,432 @@
+//! Implements splitting vectors into their low and high halves,
+//! concatenating two vectors, and widening the lanes of each half.
+
+use crate::*;
+
+/// The low (`lo`) or high (`hi`) half of a vector of `2 * $n` lanes.
+///
+/// `shuffle!` does not produce vectors of one lane, which are built from the
+/// extracted lane instead.
+macro_rules! shuffle_half {
+    ($half:ident, $v:expr; lo 1) => { $half::new($v.extract(0)) };
+    ($half:ident, $v:expr; hi 1) => { $half::new($v.extract(1)) };
+    ($half:ident, $v:expr; lo 2) => { crate::shuffle!($v, [0, 1]) };
+    ($half:ident, $v:expr; hi 2) => { crate::shuffle!($v, [2, 3]) };
+    ($half:ident, $v:expr; lo 4) => { crate::shuffle!($v, [0, 1, 2, 3]) };
+    ($half:ident, $v:expr; hi 4) => { crate::shuffle!($v, [4, 5, 6, 7]) };
+    ($half:ident, $v:expr; lo 8) => { crate::shuffle!($v, [0, 1, 2, 3, 4, 5, 6, 7]) };
+    ($half:ident, $v:expr; hi 8) => { crate::shuffle!($v, [8, 9, 10, 11, 12, 13, 14, 15]) };
+    ($half:ident, $v:expr; lo 16) => {
+        crate::shuffle!($v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    };
+    ($half:ident, $v:expr; hi 16) => {
+        crate::shuffle!($v, [16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31])
+    };
+    ($half:ident, $v:expr; lo 32) => {
+        crate::shuffle!($v, [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+        ])
+    };
+    ($half:ident, $v:expr; hi 32) => {
+        crate::shuffle!($v, [
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+        ])
+    };
+}
+
+/// Concatenates two vectors of `$n` lanes into a vector of `2 * $n` lanes.
+macro_rules! shuffle_concat {
+    ($lo:expr, $hi:expr; 1) => { crate::shuffle!($lo, $hi, [0, 1]) };
+    ($lo:expr, $hi:expr; 2) => { crate::shuffle!($lo, $hi, [0, 1, 2, 3]) };
+    ($lo:expr, $hi:expr; 4) => { crate::shuffle!($lo, $hi, [0, 1, 2, 3, 4, 5, 6, 7]) };
+    ($lo:expr, $hi:expr; 8) => {
+        crate::shuffle!($lo, $hi, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+    };
+    ($lo:expr, $hi:expr; 16) => {
+        crate::shuffle!($lo, $hi, [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+        ])
+    };
+    ($lo:expr, $hi:expr; 32) => {
+        crate::shuffle!($lo, $hi, [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+        ])
+    };
+}
+
+/// A value of the `$i`-th lane of the vectors with `$lane` elements in the
+/// tests.
+macro_rules! split_concat_lane {
+    (bool, $i:expr) => { $i % 3 == 0 };
+    ($lane:ident, $i:expr) => { $i as $lane };
+}
+
+macro_rules! impl_split_concat {
+    ($id:ident[$test_tt:tt]: $half:ident | $n:tt | $lane:ident) => {
+        impl $id {
+            /// Splits the vector into its low and high halves.
+            ///
+            /// The first vector of the result contains the lanes `[0, N / 2)`
+            /// of `self`, and the second one the lanes `[N / 2, N)`.
+            #[inline]
+            pub fn split(self) -> ($half, $half) {
+                (shuffle_half!($half, self; lo $n), shuffle_half!($half, self; hi $n))
+            }
+
+            /// Concatenates two vectors.
+            ///
+            /// The lanes of `lo` are the low half of the result, and the lanes
+            /// of `hi` its high half. This is the inverse of
+            /// [`split`](#method.split).
+            #[inline]
+            pub fn concat(lo: $half, hi: $half) -> Self {
+                shuffle_concat!(lo, hi; $n)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _split_concat>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn split_concat() {
+                        let mut v = $id::splat(split_concat_lane!($lane, 0));
+                        for i in 0..$id::lanes() {
+                            v = v.replace(i, split_concat_lane!($lane, i));
+                        }
+                        let (lo, hi) = v.split();
+                        for i in 0..$half::lanes() {
+                            assert_eq!(lo.extract(i), v.extract(i));
+                            assert_eq!(hi.extract(i), v.extract(i + $half::lanes()));
+                        }
+                        assert_eq!($id::concat(lo, hi), v);
+                        assert_eq!($id::concat(hi, lo).split(), (hi, lo));
+                    }
+                }
+            }
+        }
+    };
+    (<T> $id:ident[$test_tt:tt]: $half:ident | $n:tt) => {
+        impl<T> $id<T> {
+            /// Splits the vector into its low and high halves.
+            ///
+            /// The first vector of the result contains the lanes `[0, N / 2)`
+            /// of `self`, and the second one the lanes `[N / 2, N)`.
+            #[inline]
+            pub fn split(self) -> ($half<T>, $half<T>) {
+                (shuffle_half!($half, self; lo $n), shuffle_half!($half, self; hi $n))
+            }
+
+            /// Concatenates two vectors.
+            ///
+            /// The lanes of `lo` are the low half of the result, and the lanes
+            /// of `hi` its high half. This is the inverse of
+            /// [`split`](#method.split).
+            #[inline]
+            pub fn concat(lo: $half<T>, hi: $half<T>) -> Self {
+                shuffle_concat!(lo, hi; $n)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _split_concat>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn split_concat() {
+                        let mut a = [0_i32; 8];
+                        let mut v = $id::<i32>::null();
+                        for i in 0..$id::<i32>::lanes() {
+                            v = v.replace(i, a[i..].as_mut_ptr() as _);
+                        }
+                        let (lo, hi) = v.split();
+                        for i in 0..$half::<i32>::lanes() {
+                            assert_eq!(lo.extract(i), v.extract(i));
+                            assert_eq!(hi.extract(i), v.extract(i + $half::<i32>::lanes()));
+                        }
+                        assert!($id::concat(lo, hi) == v);
+                        let (hi2, lo2) = $id::concat(hi, lo).split();
+                        assert!(hi2 == hi && lo2 == lo);
+                    }
+                }
+            }
+        }
+    };
+    // Vectors of one lane are not available for lanes narrower than 128 bits,
+    // so vectors of two lanes are split into their lanes.
+    ($id:ident[$test_tt:tt]: | $lane:ident) => {
+        impl $id {
+            /// Splits the vector into its two lanes.
+            ///
+            /// Vectors of one lane are not available for these lanes, so the
+            /// halves of the vector are `(self.extract(0), self.extract(1))`.
+            #[inline]
+            pub fn split(self) -> ($lane, $lane) {
+                (self.extract(0), self.extract(1))
+            }
+
+            /// Concatenates two lanes.
+            ///
+            /// This is `new(lo, hi)`, and the inverse of
+            /// [`split`](#method.split).
+            #[inline]
+            pub fn concat(lo: $lane, hi: $lane) -> Self {
+                Self::new(lo, hi)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _split_concat>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn split_concat() {
+                        let v = $id::new(
+                            split_concat_lane!($lane, 0),
+                            split_concat_lane!($lane, 1),
+                        );
+                        let (lo, hi) = v.split();
+                        assert_eq!((lo, hi), (v.extract(0), v.extract(1)));
+                        assert_eq!($id::concat(lo, hi), v);
+                        assert_eq!($id::concat(hi, lo).split(), (hi, lo));
+                    }
+                }
+            }
+        }
+    };
+    (<T> $id:ident[$test_tt:tt]: | $lane:ty) => {
+        impl<T> $id<T> {
+            /// Splits the vector into its two lanes.
+            ///
+            /// Vectors of one pointer are not available, so the halves of the
+            /// vector are `(self.extract(0), self.extract(1))`.
+            #[inline]
+            pub fn split(self) -> ($lane, $lane) {
+                (self.extract(0), self.extract(1))
+            }
+
+            /// Concatenates two lanes.
+            ///
+            /// This is `new(lo, hi)`, and the inverse of
+            /// [`split`](#method.split).
+            #[inline]
+            pub fn concat(lo: $lane, hi: $lane) -> Self {
+                Self::new(lo, hi)
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _split_concat>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn split_concat() {
+                        let mut a = [0_i32; 2];
+                        let v = $id::<i32>::new(a.as_mut_ptr() as _, a[1..].as_mut_ptr() as _);
+                        let (lo, hi) = v.split();
+                        assert_eq!((lo, hi), (v.extract(0), v.extract(1)));
+                        assert!($id::concat(lo, hi) == v);
+                        assert_eq!($id::concat(hi, lo).split(), (hi, lo));
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_widen {
+    ($id:ident[$test_tt:tt]: $wide:ident) => {
+        impl_widen!(
+            $id[$test_tt]: $wide,
+            |v| $wide::from_cast(v.split().0),
+            |v| $wide::from_cast(v.split().1)
+        );
+    };
+    // The one-lane halves of 64-bit lanes (e.g. `i64x1`) are not available,
+    // so the lanes are converted directly.
+    ($id:ident[$test_tt:tt]: $wide:ident | $welem:ident) => {
+        impl_widen!(
+            $id[$test_tt]: $wide,
+            |v| $wide::new(v.extract(0) as $welem),
+            |v| $wide::new(v.extract(1) as $welem)
+        );
+    };
+    ($id:ident[$test_tt:tt]: $wide:ident,
+     |$lo:ident| $lo_expr:expr, |$hi:ident| $hi_expr:expr) => {
+        impl $id {
+            /// Widens the low half of the lanes.
+            ///
+            /// The lanes `[0, N / 2)` of `self` are converted to lanes twice
+            /// as wide, as with `FromCast`: integers are sign- or
+            /// zero-extended, and `f32`s are converted to `f64`s exactly.
+            #[inline]
+            pub fn widen_low(self) -> $wide {
+                let $lo = self;
+                $lo_expr
+            }
+
+            /// Widens the high half of the lanes.
+            ///
+            /// The lanes `[N / 2, N)` of `self` are converted to lanes twice
+            /// as wide, as with `FromCast`: integers are sign- or
+            /// zero-extended, and `f32`s are converted to `f64`s exactly.
+            #[inline]
+            pub fn widen_high(self) -> $wide {
+                let $hi = self;
+                $hi_expr
+            }
+        }
+
+        test_if!{
+            $test_tt:
+            paste::item! {
+                pub mod [<$id _widen>] {
+                    use super::*;
+                    #[cfg_attr(not(target_arch = "wasm32"), test)] #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                    fn widen() {
+                        type E = <$id as crate::sealed::Simd>::Element;
+                        type W = <$wide as crate::sealed::Simd>::Element;
+                        // [0, -1, -2, ...], which wraps for unsigned lanes:
+                        let mut v = $id::splat(0 as E);
+                        for i in 0..$id::lanes() {
+                            v = v.replace(i, i as E);
+                        }
+                        let v = $id::splat(0 as E) - v;
+                        let (lo, hi) = (v.widen_low(), v.widen_high());
+                        for i in 0..$wide::lanes() {
+                            assert_eq!(lo.extract(i), v.extract(i) as W);
+                            assert_eq!(hi.extract(i), v.extract(i + $wide::lanes()) as W);
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_split_concat!(i8x2[test_v16]: | i8);
+impl_split_concat!(u8x2[test_v16]: | u8);
+impl_split_concat!(m8x2[test_v16]: | bool);
+impl_split_concat!(i8x4[test_v32]: i8x2 | 2 | i8);
+impl_split_concat!(u8x4[test_v32]: u8x2 | 2 | u8);
+impl_split_concat!(m8x4[test_v32]: m8x2 | 2 | bool);
+impl_split_concat!(i16x2[test_v32]: | i16);
+impl_split_concat!(u16x2[test_v32]: | u16);
+impl_split_concat!(m16x2[test_v32]: | bool);
+impl_split_concat!(i8x8[test_v64]: i8x4 | 4 | i8);
+impl_split_concat!(i16x4[test_v64]: i16x2 | 2 | i16);
+impl_split_concat!(u8x8[test_v64]: u8x4 | 4 | u8);
+impl_split_concat!(u16x4[test_v64]: u16x2 | 2 | u16);
+impl_split_concat!(m8x8[test_v64]: m8x4 | 4 | bool);
+impl_split_concat!(m16x4[test_v64]: m16x2 | 2 | bool);
+impl_split_concat!(i32x2[test_v64]: | i32);
+impl_split_concat!(u32x2[test_v64]: | u32);
+impl_split_concat!(f32x2[test_v64]: | f32);
+impl_split_concat!(m32x2[test_v64]: | bool);
+impl_split_concat!(i8x16[test_v128]: i8x8 | 8 | i8);
+impl_split_concat!(i16x8[test_v128]: i16x4 | 4 | i16);
+impl_split_concat!(i32x4[test_v128]: i32x2 | 2 | i32);
+impl_split_concat!(u8x16[test_v128]: u8x8 | 8 | u8);
+impl_split_concat!(u16x8[test_v128]: u16x4 | 4 | u16);
+impl_split_concat!(u32x4[test_v128]: u32x2 | 2 | u32);
+impl_split_concat!(f32x4[test_v128]: f32x2 | 2 | f32);
+impl_split_concat!(m8x16[test_v128]: m8x8 | 8 | bool);
+impl_split_concat!(m16x8[test_v128]: m16x4 | 4 | bool);
+impl_split_concat!(m32x4[test_v128]: m32x2 | 2 | bool);
+impl_split_concat!(i64x2[test_v128]: | i64);
+impl_split_concat!(u64x2[test_v128]: | u64);
+impl_split_concat!(f64x2[test_v128]: | f64);
+impl_split_concat!(m64x2[test_v128]: | bool);
+impl_split_concat!(i8x32[test_v256]: i8x16 | 16 | i8);
+impl_split_concat!(i16x16[test_v256]: i16x8 | 8 | i16);
+impl_split_concat!(i32x8[test_v256]: i32x4 | 4 | i32);
+impl_split_concat!(i64x4[test_v256]: i64x2 | 2 | i64);
+impl_split_concat!(i128x2[test_v256]: i128x1 | 1 | i128);
+impl_split_concat!(u8x32[test_v256]: u8x16 | 16 | u8);
+impl_split_concat!(u16x16[test_v256]: u16x8 | 8 | u16);
+impl_split_concat!(u32x8[test_v256]: u32x4 | 4 | u32);
+impl_split_concat!(u64x4[test_v256]: u64x2 | 2 | u64);
+impl_split_concat!(u128x2[test_v256]: u128x1 | 1 | u128);
+impl_split_concat!(f32x8[test_v256]: f32x4 | 4 | f32);
+impl_split_concat!(f64x4[test_v256]: f64x2 | 2 | f64);
+impl_split_concat!(m8x32[test_v256]: m8x16 | 16 | bool);
+impl_split_concat!(m16x16[test_v256]: m16x8 | 8 | bool);
+impl_split_concat!(m32x8[test_v256]: m32x4 | 4 | bool);
+impl_split_concat!(m64x4[test_v256]: m64x2 | 2 | bool);
+impl_split_concat!(m128x2[test_v256]: m128x1 | 1 | bool);
+impl_split_concat!(i8x64[test_v512]: i8x32 | 32 | i8);
+impl_split_concat!(i16x32[test_v512]: i16x16 | 16 | i16);
+impl_split_concat!(i32x16[test_v512]: i32x8 | 8 | i32);
+impl_split_concat!(i64x8[test_v512]: i64x4 | 4 | i64);
+impl_split_concat!(i128x4[test_v512]: i128x2 | 2 | i128);
+impl_split_concat!(u8x64[test_v512]: u8x32 | 32 | u8);
+impl_split_concat!(u16x32[test_v512]: u16x16 | 16 | u16);
+impl_split_concat!(u32x16[test_v512]: u32x8 | 8 | u32);
+impl_split_concat!(u64x8[test_v512]: u64x4 | 4 | u64);
+impl_split_concat!(u128x4[test_v512]: u128x2 | 2 | u128);
+impl_split_concat!(f32x16[test_v512]: f32x8 | 8 | f32);
+impl_split_concat!(f64x8[test_v512]: f64x4 | 4 | f64);
+impl_split_concat!(m8x64[test_v512]: m8x32 | 32 | bool);
+impl_split_concat!(m16x32[test_v512]: m16x16 | 16 | bool);
+impl_split_concat!(m32x16[test_v512]: m32x8 | 8 | bool);
+impl_split_concat!(m64x8[test_v512]: m64x4 | 4 | bool);
+impl_split_concat!(m128x4[test_v512]: m128x2 | 2 | bool);
+impl_split_concat!(isizex2[test_v128]: | isize);
+impl_split_concat!(isizex4[test_v256]: isizex2 | 2 | isize);
+impl_split_concat!(isizex8[test_v512]: isizex4 | 4 | isize);
+impl_split_concat!(usizex2[test_v128]: | usize);
+impl_split_concat!(usizex4[test_v256]: usizex2 | 2 | usize);
+impl_split_concat!(usizex8[test_v512]: usizex4 | 4 | usize);
+impl_split_concat!(msizex2[test_v128]: | bool);
+impl_split_concat!(msizex4[test_v256]: msizex2 | 2 | bool);
+impl_split_concat!(msizex8[test_v512]: msizex4 | 4 | bool);
+impl_split_concat!(<T> cptrx2[test_v128]: | *const T);
+impl_split_concat!(<T> cptrx4[test_v256]: cptrx2 | 2);
+impl_split_concat!(<T> cptrx8[test_v512]: cptrx4 | 4);
+impl_split_concat!(<T> mptrx2[test_v128]: | *mut T);
+impl_split_concat!(<T> mptrx4[test_v256]: mptrx2 | 2);
+impl_split_concat!(<T> mptrx8[test_v512]: mptrx4 | 4);
+
+impl_widen!(i8x4[test_v32]: i16x2);
+impl_widen!(u8x4[test_v32]: u16x2);
+impl_widen!(i8x8[test_v64]: i16x4);
+impl_widen!(i16x4[test_v64]: i32x2);
+impl_widen!(u8x8[test_v64]: u16x4);
+impl_widen!(u16x4[test_v64]: u32x2);
+impl_widen!(i8x16[test_v128]: i16x8);
+impl_widen!(i16x8[test_v128]: i32x4);
+impl_widen!(i32x4[test_v128]: i64x2);
+impl_widen!(u8x16[test_v128]: u16x8);
+impl_widen!(u16x8[test_v128]: u32x4);
+impl_widen!(u32x4[test_v128]: u64x2);
+impl_widen!(f32x4[test_v128]: f64x2);
+impl_widen!(i64x2[test_v128]: i128x1 | i128);
+impl_widen!(u64x2[test_v128]: u128x1 | u128);
+impl_widen!(i8x32[test_v256]: i16x16);
+impl_widen!(i16x16[test_v256]: i32x8);
+impl_widen!(i32x8[test_v256]: i64x4);
+impl_widen!(i64x4[test_v256]: i128x2);
+impl_widen!(u8x32[test_v256]: u16x16);
+impl_widen!(u16x16[test_v256]: u32x8);
+impl_widen!(u32x8[test_v256]: u64x4);
+impl_widen!(u64x4[test_v256]: u128x2);
+impl_widen!(f32x8[test_v256]: f64x4);
+impl_widen!(i8x64[test_v512]: i16x32);
+impl_widen!(i16x32[test_v512]: i32x16);
+impl_widen!(i32x16[test_v512]: i64x8);
+impl_widen!(i64x8[test_v512]: i128x4);
+impl_widen!(u8x64[test_v512]: u16x32);
+impl_widen!(u16x32[test_v512]: u32x16);
+impl_widen!(u32x16[test_v512]: u64x8);
+impl_widen!(u64x8[test_v512]: u128x4);
+impl_widen!(f32x16[test_v512]: f64x8);
@@ -1,8 +1,11 @@
 //! Code-generation utilities
 
+crate mod avg;
 crate mod bit_manip;
+crate mod funnel_shift;
 crate mod llvm;
 crate mod math;
+crate mod mul_high;
 crate mod reductions;
 crate mod shuffle;
 crate mod shuffle1_dyn;
@@ -0,0 +1,148 @@
+//! Vertical halving additions.
+#![allow(unused_macros)]
+
+use crate::*;
+
+crate trait Avg {
+    fn avg(self, y: Self) -> Self;
+    fn halving_add(self, y: Self) -> Self;
+}
+
+macro_rules! impl_avg {
+    // Overflow-free portable implementation: `a + b == 2 * (a & b) + (a ^ b)`
+    // and `a + b == 2 * (a | b) - (a ^ b)`. The shifts are logical for
+    // unsigned and arithmetic for signed vectors, so that these compute
+    // `floor((a + b) / 2)` and `ceil((a + b) / 2)` respectively for both.
+    (fallback: $($id:ident),*) => {
+        $(
+            impl Avg for $id {
+                #[inline]
+                fn avg(self, y: Self) -> Self {
+                    (self | y) - ((self ^ y) >> 1)
+                }
+                #[inline]
+                fn halving_add(self, y: Self) -> Self {
+                    (self & y) + ((self ^ y) >> 1)
+                }
+            }
+        )*
+    };
+    // `pavg{b,w}`: only the unsigned rounding average is available.
+    (x86: $id:ident, $avg:ident) => {
+        impl Avg for $id {
+            #[inline]
+            fn avg(self, y: Self) -> Self {
+                #[cfg(target_arch = "x86")]
+                use crate::arch::x86::$avg;
+                #[cfg(target_arch = "x86_64")]
+                use crate::arch::x86_64::$avg;
+                use crate::mem::transmute;
+                // This is safe because the binary is compiled with the
+                // required target features enabled at compile-time.
+                unsafe { transmute($avg(transmute(self), transmute(y))) }
+            }
+            #[inline]
+            fn halving_add(self, y: Self) -> Self {
+                (self & y) + ((self ^ y) >> 1)
+            }
+        }
+    };
+    // `{s,u}rhadd` and `{s,u}hadd`
+    (neon: $id:ident, $rhadd:ident, $hadd:ident) => {
+        impl Avg for $id {
+            #[inline]
+            fn avg(self, y: Self) -> Self {
+                use crate::arch::aarch64::$rhadd;
+                use crate::mem::transmute;
+                // This is safe because the binary is compiled with neon
+                // enabled at compile-time.
+                unsafe { transmute($rhadd(transmute(self), transmute(y))) }
+            }
+            #[inline]
+            fn halving_add(self, y: Self) -> Self {
+                use crate::arch::aarch64::$hadd;
+                use crate::mem::transmute;
+                unsafe { transmute($hadd(transmute(self), transmute(y))) }
+            }
+        }
+    };
+    // Computes the result of each half of the vector separately:
+    (halves: $id:ident, $half_id:ident) => {
+        impl Avg for $id {
+            #[inline]
+            fn avg(self, y: Self) -> Self {
+                unsafe {
+                    use crate::mem::transmute;
+                    let x: [$half_id; 2] = transmute(self);
+                    let y: [$half_id; 2] = transmute(y);
+                    transmute([Avg::avg(x[0], y[0]), Avg::avg(x[1], y[1])])
+                }
+            }
+            #[inline]
+            fn halving_add(self, y: Self) -> Self {
+                unsafe {
+                    use crate::mem::transmute;
+                    let x: [$half_id; 2] = transmute(self);
+                    let y: [$half_id; 2] = transmute(y);
+                    transmute([
+                        Avg::halving_add(x[0], y[0]),
+                        Avg::halving_add(x[1], y[1]),
+                    ])
+                }
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                 target_feature = "avx2"))] {
+        impl_avg!(x86: u8x16, _mm_avg_epu8);
+        impl_avg!(x86: u16x8, _mm_avg_epu16);
+        impl_avg!(x86: u8x32, _mm256_avg_epu8);
+        impl_avg!(x86: u16x16, _mm256_avg_epu16);
+        impl_avg!(halves: u8x64, u8x32);
+        impl_avg!(halves: u16x32, u16x16);
+
+        impl_avg!(fallback: u8x2, u8x4, u8x8, u16x2, u16x4);
+        impl_avg!(fallback: i8x2, i8x4, i8x8, i8x16, i8x32, i8x64);
+        impl_avg!(fallback: i16x2, i16x4, i16x8, i16x16, i16x32);
+    } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = "sse2"))] {
+        impl_avg!(x86: u8x16, _mm_avg_epu8);
+        impl_avg!(x86: u16x8, _mm_avg_epu16);
+        impl_avg!(halves: u8x32, u8x16);
+        impl_avg!(halves: u16x16, u16x8);
+        impl_avg!(halves: u8x64, u8x32);
+        impl_avg!(halves: u16x32, u16x16);
+
+        impl_avg!(fallback: u8x2, u8x4, u8x8, u16x2, u16x4);
+        impl_avg!(fallback: i8x2, i8x4, i8x8, i8x16, i8x32, i8x64);
+        impl_avg!(fallback: i16x2, i16x4, i16x8, i16x16, i16x32);
+    } else if #[cfg(all(target_arch = "aarch64", target_feature = "neon",
+                        any(feature = "core_arch", libcore_neon)))] {
+        impl_avg!(neon: u8x8, vrhadd_u8, vhadd_u8);
+        impl_avg!(neon: u8x16, vrhaddq_u8, vhaddq_u8);
+        impl_avg!(neon: u16x4, vrhadd_u16, vhadd_u16);
+        impl_avg!(neon: u16x8, vrhaddq_u16, vhaddq_u16);
+        impl_avg!(neon: i8x8, vrhadd_s8, vhadd_s8);
+        impl_avg!(neon: i8x16, vrhaddq_s8, vhaddq_s8);
+        impl_avg!(neon: i16x4, vrhadd_s16, vhadd_s16);
+        impl_avg!(neon: i16x8, vrhaddq_s16, vhaddq_s16);
+        impl_avg!(halves: u8x32, u8x16);
+        impl_avg!(halves: u8x64, u8x32);
+        impl_avg!(halves: u16x16, u16x8);
+        impl_avg!(halves: u16x32, u16x16);
+        impl_avg!(halves: i8x32, i8x16);
+        impl_avg!(halves: i8x64, i8x32);
+        impl_avg!(halves: i16x16, i16x8);
+        impl_avg!(halves: i16x32, i16x16);
+
+        impl_avg!(fallback: u8x2, u8x4, u16x2, i8x2, i8x4, i16x2);
+    } else {
+        impl_avg!(fallback: u8x2, u8x4, u8x8, u8x16, u8x32, u8x64);
+        impl_avg!(fallback: u16x2, u16x4, u16x8, u16x16, u16x32);
+        impl_avg!(fallback: i8x2, i8x4, i8x8, i8x16, i8x32, i8x64);
+        impl_avg!(fallback: i16x2, i16x4, i16x8, i16x16, i16x32);
+    }
+}
@@ -145,17 +145,65 @@ extern "C" {
     fn ctpop_u128x2(x: u128x2) -> u128x2;
     #[link_name = "llvm.ctpop.v4i128"]
     fn ctpop_u128x4(x: u128x4) -> u128x4;
+
+    #[link_name = "llvm.bitreverse.v2i8"]
+    fn bitreverse_u8x2(x: u8x2) -> u8x2;
+    #[link_name = "llvm.bitreverse.v4i8"]
+    fn bitreverse_u8x4(x: u8x4) -> u8x4;
+    #[link_name = "llvm.bitreverse.v8i8"]
+    fn bitreverse_u8x8(x: u8x8) -> u8x8;
+    #[link_name = "llvm.bitreverse.v16i8"]
+    fn bitreverse_u8x16(x: u8x16) -> u8x16;
+    #[link_name = "llvm.bitreverse.v32i8"]
+    fn bitreverse_u8x32(x: u8x32) -> u8x32;
+    #[link_name = "llvm.bitreverse.v64i8"]
+    fn bitreverse_u8x64(x: u8x64) -> u8x64;
+
+    #[link_name = "llvm.bitreverse.v2i16"]
+    fn bitreverse_u16x2(x: u16x2) -> u16x2;
+    #[link_name = "llvm.bitreverse.v4i16"]
+    fn bitreverse_u16x4(x: u16x4) -> u16x4;
+    #[link_name = "llvm.bitreverse.v8i16"]
+    fn bitreverse_u16x8(x: u16x8) -> u16x8;
+    #[link_name = "llvm.bitreverse.v16i16"]
+    fn bitreverse_u16x16(x: u16x16) -> u16x16;
+    #[link_name = "llvm.bitreverse.v32i16"]
+    fn bitreverse_u16x32(x: u16x32) -> u16x32;
+
+    #[link_name = "llvm.bitreverse.v2i32"]
+    fn bitreverse_u32x2(x: u32x2) -> u32x2;
+    #[link_name = "llvm.bitreverse.v4i32"]
+    fn bitreverse_u32x4(x: u32x4) -> u32x4;
+    #[link_name = "llvm.bitreverse.v8i32"]
+    fn bitreverse_u32x8(x: u32x8) -> u32x8;
+    #[link_name = "llvm.bitreverse.v16i32"]
+    fn bitreverse_u32x16(x: u32x16) -> u32x16;
+
+    #[link_name = "llvm.bitreverse.v2i64"]
+    fn bitreverse_u64x2(x: u64x2) -> u64x2;
+    #[link_name = "llvm.bitreverse.v4i64"]
+    fn bitreverse_u64x4(x: u64x4) -> u64x4;
+    #[link_name = "llvm.bitreverse.v8i64"]
+    fn bitreverse_u64x8(x: u64x8) -> u64x8;
+
+    #[link_name = "llvm.bitreverse.v1i128"]
+    fn bitreverse_u128x1(x: u128x1) -> u128x1;
+    #[link_name = "llvm.bitreverse.v2i128"]
+    fn bitreverse_u128x2(x: u128x2) -> u128x2;
+    #[link_name = "llvm.bitreverse.v4i128"]
+    fn bitreverse_u128x4(x: u128x4) -> u128x4;
 }
 
 crate trait BitManip {
     fn ctpop(self) -> Self;
     fn ctlz(self) -> Self;
     fn cttz(self) -> Self;
+    fn bitreverse(self) -> Self;
 }
 
 macro_rules! impl_bit_manip {
     (inner: $ty:ident, $scalar:ty, $uty:ident,
-     $ctpop:ident, $ctlz:ident, $cttz:ident) => {
+     $ctpop:ident, $ctlz:ident, $cttz:ident, $bitreverse:ident) => {
         // FIXME: several LLVM intrinsics break on s390x https://github.com/rust-lang-nursery/packed_simd/issues/192
         #[cfg(target_arch = "s390x")]
         impl_bit_manip! { scalar: $ty, $scalar }
@@ -180,6 +228,12 @@ macro_rules! impl_bit_manip {
                 let y: $uty = self.cast();
                 unsafe { $cttz(y, false).cast() }
             }
+
+            #[inline]
+            fn bitreverse(self) -> Self {
+                let y: $uty = self.cast();
+                unsafe { $bitreverse(y).cast() }
+            }
         }
     };
     (sized_inner: $ty:ident, $scalar:ty, $uty:ident) => {
@@ -204,6 +258,12 @@ macro_rules! impl_bit_manip {
                 let y: $uty = self.cast();
                 $uty::cttz(y).cast()
             }
+
+            #[inline]
+            fn bitreverse(self) -> Self {
+                let y: $uty = self.cast();
+                $uty::bitreverse(y).cast()
+            }
         }
     };
     (scalar: $ty:ident, $scalar:ty) => {
@@ -234,12 +294,25 @@ macro_rules! impl_bit_manip {
                 }
                 tz
             }
+
+            #[inline]
+            fn bitreverse(self) -> Self {
+                let mut rev = self;
+                for i in 0..Self::lanes() {
+                    rev = rev.replace(i, self.extract(i).reverse_bits());
+                }
+                rev
+            }
         }
     };
     ($uty:ident, $uscalar:ty, $ity:ident, $iscalar:ty,
-     $ctpop:ident, $ctlz:ident, $cttz:ident) => {
-        impl_bit_manip! { inner: $uty, $uscalar, $uty, $ctpop, $ctlz, $cttz }
-        impl_bit_manip! { inner: $ity, $iscalar, $uty, $ctpop, $ctlz, $cttz }
+     $ctpop:ident, $ctlz:ident, $cttz:ident, $bitreverse:ident) => {
+        impl_bit_manip! {
+            inner: $uty, $uscalar, $uty, $ctpop, $ctlz, $cttz, $bitreverse
+        }
+        impl_bit_manip! {
+            inner: $ity, $iscalar, $uty, $ctpop, $ctlz, $cttz, $bitreverse
+        }
     };
     (sized: $usize:ident, $uscalar:ty, $isize:ident,
      $iscalar:ty, $ty:ident) => {
@@ -248,28 +321,28 @@ macro_rules! impl_bit_manip {
     };
 }
 
-impl_bit_manip! { u8x2   ,   u8, i8x2, i8,   ctpop_u8x2,   ctlz_u8x2,   cttz_u8x2   }
-impl_bit_manip! { u8x4   ,   u8, i8x4, i8,   ctpop_u8x4,   ctlz_u8x4,   cttz_u8x4   }
+impl_bit_manip! { u8x2   ,   u8, i8x2, i8,   ctpop_u8x2,   ctlz_u8x2,   cttz_u8x2, bitreverse_u8x2 }
+impl_bit_manip! { u8x4   ,   u8, i8x4, i8,   ctpop_u8x4,   ctlz_u8x4,   cttz_u8x4, bitreverse_u8x4 }
 #[cfg(not(target_arch = "aarch64"))] // see below
-impl_bit_manip! { u8x8   ,   u8, i8x8, i8,   ctpop_u8x8,   ctlz_u8x8,   cttz_u8x8   }
-impl_bit_manip! { u8x16  ,  u8, i8x16, i8,  ctpop_u8x16,  ctlz_u8x16,  cttz_u8x16  }
-impl_bit_manip! { u8x32  ,  u8, i8x32, i8,  ctpop_u8x32,  ctlz_u8x32,  cttz_u8x32  }
-impl_bit_manip! { u8x64  ,  u8, i8x64, i8,  ctpop_u8x64,  ctlz_u8x64,  cttz_u8x64  }
-impl_bit_manip! { u16x2  ,  u16, i16x2, i16,  ctpop_u16x2,  ctlz_u16x2,  cttz_u16x2  }
-impl_bit_manip! { u16x4  ,  u16, i16x4, i16,  ctpop_u16x4,  ctlz_u16x4,  cttz_u16x4  }
-impl_bit_manip! { u16x8  ,  u16, i16x8, i16,  ctpop_u16x8,  ctlz_u16x8,  cttz_u16x8  }
-impl_bit_manip! { u16x16 , u16, i16x16, i16, ctpop_u16x16, ctlz_u16x16, cttz_u16x16 }
-impl_bit_manip! { u16x32 , u16, i16x32, i16, ctpop_u16x32, ctlz_u16x32, cttz_u16x32 }
-impl_bit_manip! { u32x2  ,  u32, i32x2, i32,  ctpop_u32x2,  ctlz_u32x2,  cttz_u32x2  }
-impl_bit_manip! { u32x4  ,  u32, i32x4, i32,  ctpop_u32x4,  ctlz_u32x4,  cttz_u32x4  }
-impl_bit_manip! { u32x8  ,  u32, i32x8, i32,  ctpop_u32x8,  ctlz_u32x8,  cttz_u32x8  }
-impl_bit_manip! { u32x16 , u32, i32x16, i32, ctpop_u32x16, ctlz_u32x16, cttz_u32x16 }
-impl_bit_manip! { u64x2  ,  u64, i64x2, i64,  ctpop_u64x2,  ctlz_u64x2,  cttz_u64x2  }
-impl_bit_manip! { u64x4  ,  u64, i64x4, i64,  ctpop_u64x4,  ctlz_u64x4,  cttz_u64x4  }
-impl_bit_manip! { u64x8  ,  u64, i64x8, i64,  ctpop_u64x8,  ctlz_u64x8,  cttz_u64x8  }
-impl_bit_manip! { u128x1 , u128, i128x1, i128, ctpop_u128x1, ctlz_u128x1, cttz_u128x1 }
-impl_bit_manip! { u128x2 , u128, i128x2, i128, ctpop_u128x2, ctlz_u128x2, cttz_u128x2 }
-impl_bit_manip! { u128x4 , u128, i128x4, i128, ctpop_u128x4, ctlz_u128x4, cttz_u128x4 }
+impl_bit_manip! { u8x8   ,   u8, i8x8, i8,   ctpop_u8x8,   ctlz_u8x8,   cttz_u8x8, bitreverse_u8x8 }
+impl_bit_manip! { u8x16  ,  u8, i8x16, i8,  ctpop_u8x16,  ctlz_u8x16,  cttz_u8x16, bitreverse_u8x16 }
+impl_bit_manip! { u8x32  ,  u8, i8x32, i8,  ctpop_u8x32,  ctlz_u8x32,  cttz_u8x32, bitreverse_u8x32 }
+impl_bit_manip! { u8x64  ,  u8, i8x64, i8,  ctpop_u8x64,  ctlz_u8x64,  cttz_u8x64, bitreverse_u8x64 }
+impl_bit_manip! { u16x2  ,  u16, i16x2, i16,  ctpop_u16x2,  ctlz_u16x2,  cttz_u16x2, bitreverse_u16x2 }
+impl_bit_manip! { u16x4  ,  u16, i16x4, i16,  ctpop_u16x4,  ctlz_u16x4,  cttz_u16x4, bitreverse_u16x4 }
+impl_bit_manip! { u16x8  ,  u16, i16x8, i16,  ctpop_u16x8,  ctlz_u16x8,  cttz_u16x8, bitreverse_u16x8 }
+impl_bit_manip! { u16x16 , u16, i16x16, i16, ctpop_u16x16, ctlz_u16x16, cttz_u16x16, bitreverse_u16x16 }
+impl_bit_manip! { u16x32 , u16, i16x32, i16, ctpop_u16x32, ctlz_u16x32, cttz_u16x32, bitreverse_u16x32 }
+impl_bit_manip! { u32x2  ,  u32, i32x2, i32,  ctpop_u32x2,  ctlz_u32x2,  cttz_u32x2, bitreverse_u32x2 }
+impl_bit_manip! { u32x4  ,  u32, i32x4, i32,  ctpop_u32x4,  ctlz_u32x4,  cttz_u32x4, bitreverse_u32x4 }
+impl_bit_manip! { u32x8  ,  u32, i32x8, i32,  ctpop_u32x8,  ctlz_u32x8,  cttz_u32x8, bitreverse_u32x8 }
+impl_bit_manip! { u32x16 , u32, i32x16, i32, ctpop_u32x16, ctlz_u32x16, cttz_u32x16, bitreverse_u32x16 }
+impl_bit_manip! { u64x2  ,  u64, i64x2, i64,  ctpop_u64x2,  ctlz_u64x2,  cttz_u64x2, bitreverse_u64x2 }
+impl_bit_manip! { u64x4  ,  u64, i64x4, i64,  ctpop_u64x4,  ctlz_u64x4,  cttz_u64x4, bitreverse_u64x4 }
+impl_bit_manip! { u64x8  ,  u64, i64x8, i64,  ctpop_u64x8,  ctlz_u64x8,  cttz_u64x8, bitreverse_u64x8 }
+impl_bit_manip! { u128x1 , u128, i128x1, i128, ctpop_u128x1, ctlz_u128x1, cttz_u128x1, bitreverse_u128x1 }
+impl_bit_manip! { u128x2 , u128, i128x2, i128, ctpop_u128x2, ctlz_u128x2, cttz_u128x2, bitreverse_u128x2 }
+impl_bit_manip! { u128x4 , u128, i128x4, i128, ctpop_u128x4, ctlz_u128x4, cttz_u128x4, bitreverse_u128x4 }
 
 #[cfg(target_arch = "aarch64")]
 impl BitManip for u8x8 {
@@ -296,6 +369,12 @@ impl BitManip for u8x8 {
         }
         tz
     }
+
+    #[inline]
+    fn bitreverse(self) -> Self {
+        let y: u8x8 = self.cast();
+        unsafe { bitreverse_u8x8(y).cast() }
+    }
 }
 #[cfg(target_arch = "aarch64")]
 impl BitManip for i8x8 {
@@ -322,6 +401,12 @@ impl BitManip for i8x8 {
         }
         tz
     }
+
+    #[inline]
+    fn bitreverse(self) -> Self {
+        let y: u8x8 = self.cast();
+        unsafe { bitreverse_u8x8(y).cast() }
+    }
 }
 
 cfg_if! {
@@ -345,3 +430,97 @@ cfg_if! {
         compile_error!("unsupported target_pointer_width");
     }
 }
+
+crate trait BitPack {
+    fn pext(self, m: Self) -> Self;
+    fn pdep(self, m: Self) -> Self;
+}
+
+macro_rules! impl_bit_pack {
+    // Vector version of the loops in Hacker's Delight, 7-4 and 7-5: visits
+    // the set bits of the mask from the least to the most significant one.
+    (portable: $($id:ident),*) => {
+        $(
+            impl BitPack for $id {
+                #[inline]
+                fn pext(self, m: Self) -> Self {
+                    let z = Self::splat(0);
+                    let mut m = m;
+                    let mut b = Self::splat(1);
+                    let mut r = z;
+                    for _ in 0..crate::mem::size_of::<Self>() * 8 / Self::lanes() {
+                        let lowest = m & (z - m);
+                        r |= (self & lowest).ne(z).select(b, z);
+                        m &= m - Self::splat(1);
+                        b <<= 1;
+                    }
+                    r
+                }
+
+                #[inline]
+                fn pdep(self, m: Self) -> Self {
+                    let z = Self::splat(0);
+                    let mut m = m;
+                    let mut b = Self::splat(1);
+                    let mut r = z;
+                    for _ in 0..crate::mem::size_of::<Self>() * 8 / Self::lanes() {
+                        let lowest = m & (z - m);
+                        r |= (self & b).ne(z).select(lowest, z);
+                        m &= m - Self::splat(1);
+                        b <<= 1;
+                    }
+                    r
+                }
+            }
+        )*
+    };
+    // BMI2 only has scalar `pext`/`pdep`, which are used on each lane.
+    (bmi2: $pext:ident, $pdep:ident | $($id:ident),*) => {
+        $(
+            impl BitPack for $id {
+                #[inline]
+                fn pext(self, m: Self) -> Self {
+                    #[cfg(target_arch = "x86")]
+                    use crate::arch::x86::$pext;
+                    #[cfg(target_arch = "x86_64")]
+                    use crate::arch::x86_64::$pext;
+                    let mut r = self;
+                    for i in 0..Self::lanes() {
+                        // This is safe because the binary is compiled with
+                        // bmi2 enabled at compile-time.
+                        let v = unsafe { $pext(self.extract(i), m.extract(i)) };
+                        r = r.replace(i, v);
+                    }
+                    r
+                }
+
+                #[inline]
+                fn pdep(self, m: Self) -> Self {
+                    #[cfg(target_arch = "x86")]
+                    use crate::arch::x86::$pdep;
+                    #[cfg(target_arch = "x86_64")]
+                    use crate::arch::x86_64::$pdep;
+                    let mut r = self;
+                    for i in 0..Self::lanes() {
+                        let v = unsafe { $pdep(self.extract(i), m.extract(i)) };
+                        r = r.replace(i, v);
+                    }
+                    r
+                }
+            }
+        )*
+    };
+}
+
+cfg_if! {
+    if #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))] {
+        impl_bit_pack!(bmi2: _pext_u32, _pdep_u32 | u32x2, u32x4, u32x8, u32x16);
+        impl_bit_pack!(bmi2: _pext_u64, _pdep_u64 | u64x2, u64x4, u64x8);
+    } else if #[cfg(all(target_arch = "x86", target_feature = "bmi2"))] {
+        impl_bit_pack!(bmi2: _pext_u32, _pdep_u32 | u32x2, u32x4, u32x8, u32x16);
+        impl_bit_pack!(portable: u64x2, u64x4, u64x8);
+    } else {
+        impl_bit_pack!(portable: u32x2, u32x4, u32x8, u32x16);
+        impl_bit_pack!(portable: u64x2, u64x4, u64x8);
+    }
+}
@@ -0,0 +1,217 @@
+//! LLVM funnel shift intrinsics.
+#[rustfmt::skip]
+
+use crate::*;
+
+#[allow(improper_ctypes, dead_code)]
+extern "C" {
+    #[link_name = "llvm.fshl.v2i8"]
+    fn fshl_u8x2(x: u8x2, y: u8x2, z: u8x2) -> u8x2;
+    #[link_name = "llvm.fshl.v4i8"]
+    fn fshl_u8x4(x: u8x4, y: u8x4, z: u8x4) -> u8x4;
+    #[link_name = "llvm.fshl.v8i8"]
+    fn fshl_u8x8(x: u8x8, y: u8x8, z: u8x8) -> u8x8;
+    #[link_name = "llvm.fshl.v16i8"]
+    fn fshl_u8x16(x: u8x16, y: u8x16, z: u8x16) -> u8x16;
+    #[link_name = "llvm.fshl.v32i8"]
+    fn fshl_u8x32(x: u8x32, y: u8x32, z: u8x32) -> u8x32;
+    #[link_name = "llvm.fshl.v64i8"]
+    fn fshl_u8x64(x: u8x64, y: u8x64, z: u8x64) -> u8x64;
+
+    #[link_name = "llvm.fshl.v2i16"]
+    fn fshl_u16x2(x: u16x2, y: u16x2, z: u16x2) -> u16x2;
+    #[link_name = "llvm.fshl.v4i16"]
+    fn fshl_u16x4(x: u16x4, y: u16x4, z: u16x4) -> u16x4;
+    #[link_name = "llvm.fshl.v8i16"]
+    fn fshl_u16x8(x: u16x8, y: u16x8, z: u16x8) -> u16x8;
+    #[link_name = "llvm.fshl.v16i16"]
+    fn fshl_u16x16(x: u16x16, y: u16x16, z: u16x16) -> u16x16;
+    #[link_name = "llvm.fshl.v32i16"]
+    fn fshl_u16x32(x: u16x32, y: u16x32, z: u16x32) -> u16x32;
+
+    #[link_name = "llvm.fshl.v2i32"]
+    fn fshl_u32x2(x: u32x2, y: u32x2, z: u32x2) -> u32x2;
+    #[link_name = "llvm.fshl.v4i32"]
+    fn fshl_u32x4(x: u32x4, y: u32x4, z: u32x4) -> u32x4;
+    #[link_name = "llvm.fshl.v8i32"]
+    fn fshl_u32x8(x: u32x8, y: u32x8, z: u32x8) -> u32x8;
+    #[link_name = "llvm.fshl.v16i32"]
+    fn fshl_u32x16(x: u32x16, y: u32x16, z: u32x16) -> u32x16;
+
+    #[link_name = "llvm.fshl.v2i64"]
+    fn fshl_u64x2(x: u64x2, y: u64x2, z: u64x2) -> u64x2;
+    #[link_name = "llvm.fshl.v4i64"]
+    fn fshl_u64x4(x: u64x4, y: u64x4, z: u64x4) -> u64x4;
+    #[link_name = "llvm.fshl.v8i64"]
+    fn fshl_u64x8(x: u64x8, y: u64x8, z: u64x8) -> u64x8;
+
+    #[link_name = "llvm.fshl.v1i128"]
+    fn fshl_u128x1(x: u128x1, y: u128x1, z: u128x1) -> u128x1;
+    #[link_name = "llvm.fshl.v2i128"]
+    fn fshl_u128x2(x: u128x2, y: u128x2, z: u128x2) -> u128x2;
+    #[link_name = "llvm.fshl.v4i128"]
+    fn fshl_u128x4(x: u128x4, y: u128x4, z: u128x4) -> u128x4;
+
+    #[link_name = "llvm.fshr.v2i8"]
+    fn fshr_u8x2(x: u8x2, y: u8x2, z: u8x2) -> u8x2;
+    #[link_name = "llvm.fshr.v4i8"]
+    fn fshr_u8x4(x: u8x4, y: u8x4, z: u8x4) -> u8x4;
+    #[link_name = "llvm.fshr.v8i8"]
+    fn fshr_u8x8(x: u8x8, y: u8x8, z: u8x8) -> u8x8;
+    #[link_name = "llvm.fshr.v16i8"]
+    fn fshr_u8x16(x: u8x16, y: u8x16, z: u8x16) -> u8x16;
+    #[link_name = "llvm.fshr.v32i8"]
+    fn fshr_u8x32(x: u8x32, y: u8x32, z: u8x32) -> u8x32;
+    #[link_name = "llvm.fshr.v64i8"]
+    fn fshr_u8x64(x: u8x64, y: u8x64, z: u8x64) -> u8x64;
+
+    #[link_name = "llvm.fshr.v2i16"]
+    fn fshr_u16x2(x: u16x2, y: u16x2, z: u16x2) -> u16x2;
+    #[link_name = "llvm.fshr.v4i16"]
+    fn fshr_u16x4(x: u16x4, y: u16x4, z: u16x4) -> u16x4;
+    #[link_name = "llvm.fshr.v8i16"]
+    fn fshr_u16x8(x: u16x8, y: u16x8, z: u16x8) -> u16x8;
+    #[link_name = "llvm.fshr.v16i16"]
+    fn fshr_u16x16(x: u16x16, y: u16x16, z: u16x16) -> u16x16;
+    #[link_name = "llvm.fshr.v32i16"]
+    fn fshr_u16x32(x: u16x32, y: u16x32, z: u16x32) -> u16x32;
+
+    #[link_name = "llvm.fshr.v2i32"]
+    fn fshr_u32x2(x: u32x2, y: u32x2, z: u32x2) -> u32x2;
+    #[link_name = "llvm.fshr.v4i32"]
+    fn fshr_u32x4(x: u32x4, y: u32x4, z: u32x4) -> u32x4;
+    #[link_name = "llvm.fshr.v8i32"]
+    fn fshr_u32x8(x: u32x8, y: u32x8, z: u32x8) -> u32x8;
+    #[link_name = "llvm.fshr.v16i32"]
+    fn fshr_u32x16(x: u32x16, y: u32x16, z: u32x16) -> u32x16;
+
+    #[link_name = "llvm.fshr.v2i64"]
+    fn fshr_u64x2(x: u64x2, y: u64x2, z: u64x2) -> u64x2;
+    #[link_name = "llvm.fshr.v4i64"]
+    fn fshr_u64x4(x: u64x4, y: u64x4, z: u64x4) -> u64x4;
+    #[link_name = "llvm.fshr.v8i64"]
+    fn fshr_u64x8(x: u64x8, y: u64x8, z: u64x8) -> u64x8;
+
+    #[link_name = "llvm.fshr.v1i128"]
+    fn fshr_u128x1(x: u128x1, y: u128x1, z: u128x1) -> u128x1;
+    #[link_name = "llvm.fshr.v2i128"]
+    fn fshr_u128x2(x: u128x2, y: u128x2, z: u128x2) -> u128x2;
+    #[link_name = "llvm.fshr.v4i128"]
+    fn fshr_u128x4(x: u128x4, y: u128x4, z: u128x4) -> u128x4;
+}
+
+crate trait FunnelShift {
+    fn fshl(self, y: Self, n: Self) -> Self;
+    fn fshr(self, y: Self, n: Self) -> Self;
+}
+
+macro_rules! impl_funnel_shift {
+    (inner: $ty:ident, $uty:ident, $uscalar:ty, $fshl:ident, $fshr:ident) => {
+        // FIXME: several LLVM intrinsics break on s390x https://github.com/rust-lang-nursery/packed_simd/issues/192
+        #[cfg(target_arch = "s390x")]
+        impl_funnel_shift! { portable: $ty, $uty, $uscalar }
+        #[cfg(not(target_arch = "s390x"))]
+        impl FunnelShift for $ty {
+            #[inline]
+            fn fshl(self, y: Self, n: Self) -> Self {
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                unsafe { $fshl(x, y, n).cast() }
+            }
+
+            #[inline]
+            fn fshr(self, y: Self, n: Self) -> Self {
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                unsafe { $fshr(x, y, n).cast() }
+            }
+        }
+    };
+    (sized_inner: $ty:ident, $uty:ident) => {
+        impl FunnelShift for $ty {
+            #[inline]
+            fn fshl(self, y: Self, n: Self) -> Self {
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                FunnelShift::fshl(x, y, n).cast()
+            }
+
+            #[inline]
+            fn fshr(self, y: Self, n: Self) -> Self {
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                FunnelShift::fshr(x, y, n).cast()
+            }
+        }
+    };
+    // Computes the funnel shifts with logical shifts of the unsigned vector
+    // type. Shifting by the full bit width is not defined, so the bits of the
+    // second operand are shifted out in two steps.
+    (portable: $ty:ident, $uty:ident, $uscalar:ty) => {
+        impl FunnelShift for $ty {
+            #[inline]
+            fn fshl(self, y: Self, n: Self) -> Self {
+                const M: $uscalar = crate::mem::size_of::<$uscalar>() as $uscalar * 8 - 1;
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                let n = n & $uty::splat(M);
+                ((x << n) | ((y >> 1) >> ($uty::splat(M) - n))).cast()
+            }
+
+            #[inline]
+            fn fshr(self, y: Self, n: Self) -> Self {
+                const M: $uscalar = crate::mem::size_of::<$uscalar>() as $uscalar * 8 - 1;
+                let (x, y, n): ($uty, $uty, $uty) = (self.cast(), y.cast(), n.cast());
+                let n = n & $uty::splat(M);
+                (((x << 1) << ($uty::splat(M) - n)) | (y >> n)).cast()
+            }
+        }
+    };
+    ($uty:ident, $uscalar:ty, $ity:ident, $fshl:ident, $fshr:ident) => {
+        impl_funnel_shift! { inner: $uty, $uty, $uscalar, $fshl, $fshr }
+        impl_funnel_shift! { inner: $ity, $uty, $uscalar, $fshl, $fshr }
+    };
+    (sized: $usize:ident, $isize:ident, $ty:ident) => {
+        impl_funnel_shift! { sized_inner: $usize, $ty }
+        impl_funnel_shift! { sized_inner: $isize, $ty }
+    };
+}
+
+impl_funnel_shift! { u8x2, u8, i8x2, fshl_u8x2, fshr_u8x2 }
+impl_funnel_shift! { u8x4, u8, i8x4, fshl_u8x4, fshr_u8x4 }
+impl_funnel_shift! { u8x8, u8, i8x8, fshl_u8x8, fshr_u8x8 }
+impl_funnel_shift! { u8x16, u8, i8x16, fshl_u8x16, fshr_u8x16 }
+impl_funnel_shift! { u8x32, u8, i8x32, fshl_u8x32, fshr_u8x32 }
+impl_funnel_shift! { u8x64, u8, i8x64, fshl_u8x64, fshr_u8x64 }
+impl_funnel_shift! { u16x2, u16, i16x2, fshl_u16x2, fshr_u16x2 }
+impl_funnel_shift! { u16x4, u16, i16x4, fshl_u16x4, fshr_u16x4 }
+impl_funnel_shift! { u16x8, u16, i16x8, fshl_u16x8, fshr_u16x8 }
+impl_funnel_shift! { u16x16, u16, i16x16, fshl_u16x16, fshr_u16x16 }
+impl_funnel_shift! { u16x32, u16, i16x32, fshl_u16x32, fshr_u16x32 }
+impl_funnel_shift! { u32x2, u32, i32x2, fshl_u32x2, fshr_u32x2 }
+impl_funnel_shift! { u32x4, u32, i32x4, fshl_u32x4, fshr_u32x4 }
+impl_funnel_shift! { u32x8, u32, i32x8, fshl_u32x8, fshr_u32x8 }
+impl_funnel_shift! { u32x16, u32, i32x16, fshl_u32x16, fshr_u32x16 }
+impl_funnel_shift! { u64x2, u64, i64x2, fshl_u64x2, fshr_u64x2 }
+impl_funnel_shift! { u64x4, u64, i64x4, fshl_u64x4, fshr_u64x4 }
+impl_funnel_shift! { u64x8, u64, i64x8, fshl_u64x8, fshr_u64x8 }
+impl_funnel_shift! { u128x1, u128, i128x1, fshl_u128x1, fshr_u128x1 }
+impl_funnel_shift! { u128x2, u128, i128x2, fshl_u128x2, fshr_u128x2 }
+impl_funnel_shift! { u128x4, u128, i128x4, fshl_u128x4, fshr_u128x4 }
+
+cfg_if! {
+    if #[cfg(target_pointer_width = "8")] {
+        impl_funnel_shift! { sized: usizex2, isizex2, u8x2 }
+        impl_funnel_shift! { sized: usizex4, isizex4, u8x4 }
+        impl_funnel_shift! { sized: usizex8, isizex8, u8x8 }
+    } else if #[cfg(target_pointer_width = "16")] {
+        impl_funnel_shift! { sized: usizex2, isizex2, u16x2 }
+        impl_funnel_shift! { sized: usizex4, isizex4, u16x4 }
+        impl_funnel_shift! { sized: usizex8, isizex8, u16x8 }
+    } else if #[cfg(target_pointer_width = "32")] {
+        impl_funnel_shift! { sized: usizex2, isizex2, u32x2 }
+        impl_funnel_shift! { sized: usizex4, isizex4, u32x4 }
+        impl_funnel_shift! { sized: usizex8, isizex8, u32x8 }
+    } else if #[cfg(target_pointer_width = "64")] {
+        impl_funnel_shift! { sized: usizex2, isizex2, u64x2 }
+        impl_funnel_shift! { sized: usizex4, isizex4, u64x4 }
+        impl_funnel_shift! { sized: usizex8, isizex8, u64x8 }
+    } else {
+        compile_error!("unsupported target_pointer_width");
+    }
+}
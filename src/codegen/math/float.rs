@@ -4,16 +4,46 @@
 #[macro_use]
 crate mod macros;
 crate mod abs;
+crate mod acos;
+crate mod acosh;
+crate mod asin;
+crate mod asinh;
+crate mod atan;
+crate mod atan2;
+crate mod atanh;
+crate mod cbrt;
+crate mod ceil;
 crate mod cos;
+crate mod cos_fast;
 crate mod cos_pi;
+crate mod cosh;
+crate mod erf;
+crate mod erfc;
 crate mod exp;
+crate mod exp10;
+crate mod exp2;
+crate mod exp_fast;
+crate mod exp_m1;
+crate mod floor;
+crate mod hypot;
+crate mod lgamma;
 crate mod ln;
+crate mod ln_1p;
+crate mod ln_fast;
+crate mod log10;
+crate mod log2;
 crate mod mul_add;
 crate mod mul_adde;
 crate mod powf;
+crate mod round;
+crate mod round_ties_even;
 crate mod sin;
 crate mod sin_cos_pi;
+crate mod sin_fast;
 crate mod sin_pi;
+crate mod sinh;
 crate mod sqrt;
 crate mod sqrte;
 crate mod tanh;
+crate mod tgamma;
+crate mod trunc;
@@ -0,0 +1,112 @@
+//! Vertical floating-point `acos`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+use super::asin::AsinR;
+
+crate trait Acos {
+    fn acos(self) -> Self;
+}
+
+macro_rules! define_acos {
+    ($name:ident, $id:ident, $pio2_hi:expr, $pio2_lo:expr) => {
+        fn $name(x: $id) -> $id {
+            let half = $id::splat(0.5);
+            let two = $id::splat(2.);
+            let pio2_hi = $id::splat($pio2_hi);
+            let pio2_lo = $id::splat($pio2_lo);
+            let a = x.abs();
+            let big = a.gt(half);
+            // Out-of-domain inputs produce a NaN in the square root.
+            let z = big.select(($id::splat(1.) - a) * half, x * x);
+            let s = big.select(z.sqrt(), x);
+            let w = s * AsinR::asin_r(z);
+            // |x| <= 0.5: acos(x) = pi/2 - asin(x)
+            let small = pio2_hi - (s - (pio2_lo - w));
+            // x > 0.5: acos(x) = 2 * asin(sqrt((1 - x) / 2))
+            let pos = two * (s + w);
+            // x < -0.5: acos(x) = pi - 2 * asin(sqrt((1 + x) / 2))
+            let neg = two * (pio2_hi - (s + (w - pio2_lo)));
+            big.select(x.lt($id::splat(0.)).select(neg, pos), small)
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_acos!($name, $id, 1.570_796_251_3e+00, 7.549_789_415_9e-08);
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_acos!(
+            $name, $id,
+            1.570_796_326_794_896_558_00e+00, 6.123_233_995_736_766_035_87e-17
+        );
+    };
+}
+
+define_acos!(f32 => acos_v2f32, f32x2, u32x2);
+define_acos!(f32 => acos_v4f32, f32x4, u32x4);
+define_acos!(f32 => acos_v8f32, f32x8, u32x8);
+define_acos!(f32 => acos_v16f32, f32x16, u32x16);
+
+define_acos!(f64 => acos_v2f64, f64x2, u64x2);
+define_acos!(f64 => acos_v4f64, f64x4, u64x4);
+define_acos!(f64 => acos_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Acos, acos);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acosf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_acosf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_acosd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_acosf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_acosf8_u10avx2);
+                impl_unary!(f64x2: Sleef_acosd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_acosd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acosf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_acosf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_acosd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_acosf4_u10sse4);
+                impl_unary!(f32x8: Sleef_acosf8_u10avx);
+                impl_unary!(f64x2: Sleef_acosd2_u10sse4);
+                impl_unary!(f64x4: Sleef_acosd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acosf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_acosf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_acosd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_acosf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_acosf4_u10sse4);
+                impl_unary!(f64x2: Sleef_acosd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_acosd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: acos_v2f32);
+                impl_unary!(f32x4: acos_v4f32);
+                impl_unary!(f32x8: acos_v8f32);
+                impl_unary!(f32x16: acos_v16f32);
+
+                impl_unary!(f64x2: acos_v2f64);
+                impl_unary!(f64x4: acos_v4f64);
+                impl_unary!(f64x8: acos_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: acos_v2f32);
+        impl_unary!(f32x4: acos_v4f32);
+        impl_unary!(f32x8: acos_v8f32);
+        impl_unary!(f32x16: acos_v16f32);
+
+        impl_unary!(f64x2: acos_v2f64);
+        impl_unary!(f64x4: acos_v4f64);
+        impl_unary!(f64x8: acos_v8f64);
+    }
+}
@@ -0,0 +1,106 @@
+//! Vertical floating-point `acosh`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+use super::ln_1p::Ln1p;
+
+crate trait Acosh {
+    fn acosh(self) -> Self;
+}
+
+macro_rules! define_acosh {
+    ($name:ident, $id:ident, $big:expr, $ln_2:expr) => {
+        fn $name(x: $id) -> $id {
+            let one = $id::splat(1.);
+            let two = $id::splat(2.);
+            // x < 2: ln_1p(x - 1 + sqrt((x - 1)^2 + 2(x - 1)))
+            let t = x - one;
+            let small = Ln1p::ln_1p(t + (t * t + two * t).sqrt());
+            // x < big: ln(2x - 1 / (x + sqrt(x^2 - 1)))
+            let mid = (two * x - one / (x + (x * x - one).sqrt())).ln();
+            // otherwise x^2 - 1 == x^2 and acosh(x) = ln(2x):
+            let large = x.ln() + $id::splat($ln_2);
+            let r = x.lt(two)
+                .select(small, x.lt($id::splat($big)).select(mid, large));
+            // For `x < -1` the square root above is real, so out-of-domain
+            // inputs are mapped to NaN explicitly.
+            x.lt(one).select($id::NAN, r)
+        }
+    };
+    (f32 => $name:ident, $id:ident) => {
+        define_acosh!($name, $id, 4096., crate::f32::consts::LN_2);
+    };
+    (f64 => $name:ident, $id:ident) => {
+        define_acosh!($name, $id, 67_108_864., crate::f64::consts::LN_2);
+    };
+}
+
+define_acosh!(f32 => acosh_v2f32, f32x2);
+define_acosh!(f32 => acosh_v4f32, f32x4);
+define_acosh!(f32 => acosh_v8f32, f32x8);
+define_acosh!(f32 => acosh_v16f32, f32x16);
+
+define_acosh!(f64 => acosh_v2f64, f64x2);
+define_acosh!(f64 => acosh_v4f64, f64x4);
+define_acosh!(f64 => acosh_v8f64, f64x8);
+
+gen_unary_impl_table!(Acosh, acosh);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acoshf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_acoshf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_acoshd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_acoshf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_acoshf8_u10avx2);
+                impl_unary!(f64x2: Sleef_acoshd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_acoshd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acoshf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_acoshf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_acoshd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_acoshf4_u10sse4);
+                impl_unary!(f32x8: Sleef_acoshf8_u10avx);
+                impl_unary!(f64x2: Sleef_acoshd2_u10sse4);
+                impl_unary!(f64x4: Sleef_acoshd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_acoshf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_acoshf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_acoshd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_acoshf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_acoshf4_u10sse4);
+                impl_unary!(f64x2: Sleef_acoshd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_acoshd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: acosh_v2f32);
+                impl_unary!(f32x4: acosh_v4f32);
+                impl_unary!(f32x8: acosh_v8f32);
+                impl_unary!(f32x16: acosh_v16f32);
+
+                impl_unary!(f64x2: acosh_v2f64);
+                impl_unary!(f64x4: acosh_v4f64);
+                impl_unary!(f64x8: acosh_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: acosh_v2f32);
+        impl_unary!(f32x4: acosh_v4f32);
+        impl_unary!(f32x8: acosh_v8f32);
+        impl_unary!(f32x16: acosh_v16f32);
+
+        impl_unary!(f64x2: acosh_v2f64);
+        impl_unary!(f64x4: acosh_v4f64);
+        impl_unary!(f64x8: acosh_v8f64);
+    }
+}
@@ -0,0 +1,180 @@
+//! Vertical floating-point `asin`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+crate trait Asin {
+    fn asin(self) -> Self;
+}
+
+/// Rational approximation of `(asin(s) - s) / s` as a function of
+/// `z = s * s` on `[0, 0.25]`, shared by the portable `asin` and `acos`.
+///
+/// The coefficients are those of musl's `asinf.c` and `asin.c`.
+crate trait AsinR {
+    fn asin_r(self) -> Self;
+}
+
+macro_rules! impl_asin_r {
+    (f32: $($id:ident),*) => {
+        $(
+            impl AsinR for $id {
+                #[inline]
+                fn asin_r(self) -> Self {
+                    let z = self;
+                    let p = z * ($id::splat(1.666_658_669_7e-01)
+                        + z * ($id::splat(-4.274_342_209_1e-02)
+                        + z * $id::splat(-8.656_363_003_0e-03)));
+                    let q = $id::splat(1.)
+                        + z * $id::splat(-7.066_296_339_0e-01);
+                    p / q
+                }
+            }
+        )*
+    };
+    (f64: $($id:ident),*) => {
+        $(
+            impl AsinR for $id {
+                #[inline]
+                fn asin_r(self) -> Self {
+                    let z = self;
+                    let p = z * ($id::splat(1.666_666_666_666_666_574_15e-01)
+                        + z * ($id::splat(-3.255_658_186_224_009_154_05e-01)
+                        + z * ($id::splat(2.012_125_321_348_629_258_81e-01)
+                        + z * ($id::splat(-4.005_553_450_067_941_140_27e-02)
+                        + z * ($id::splat(7.915_349_942_898_145_321_76e-04)
+                        + z * $id::splat(3.479_331_075_960_211_675_70e-05))))));
+                    let q = $id::splat(1.)
+                        + z * ($id::splat(-2.403_394_911_734_414_218_78e+00)
+                        + z * ($id::splat(2.020_945_760_233_505_694_71e+00)
+                        + z * ($id::splat(-6.882_839_716_054_532_930_30e-01)
+                        + z * $id::splat(7.703_815_055_590_193_527_91e-02))));
+                    p / q
+                }
+            }
+        )*
+    };
+}
+
+impl_asin_r!(f32: f32x2, f32x4, f32x8, f32x16);
+impl_asin_r!(f64: f64x2, f64x4, f64x8);
+
+macro_rules! define_asin {
+    ($name:ident, $id:ident, $uid:ident,
+     |$a:ident, $z:ident, $s:ident, $r:ident| $big:expr) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let $a = x.abs();
+            let half = $id::splat(0.5);
+            let big = $a.ge(half);
+            // For |x| >= 0.5, asin(|x|) = pi/2 - 2 * asin(s), where
+            // s = sqrt(z) and z = (1 - |x|) / 2.
+            // Out-of-domain inputs produce a NaN in the square root.
+            let $z = big.select(($id::splat(1.) - $a) * half, $a * $a);
+            let $s = big.select($z.sqrt(), $a);
+            let $r = AsinR::asin_r($z);
+            let r = big.select($big, $s + $s * $r);
+            // asin is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_asin!($name, $id, $uid, |a, z, s, r| {
+            let pio2_hi = $id::splat(1.570_796_251_3e+00);
+            let pio2_lo = $id::splat(7.549_789_415_9e-08);
+            let r = s + s * r;
+            pio2_hi - ((r + r) - pio2_lo)
+        });
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_asin!($name, $id, $uid, |a, z, s, r| {
+            use crate::mem::transmute;
+            let pio2_hi = $id::splat(1.570_796_326_794_896_558_00e+00);
+            let pio2_lo = $id::splat(6.123_233_995_736_766_035_87e-17);
+            let two = $id::splat(2.);
+            let near_one = pio2_hi - (two * (s + s * r) - pio2_lo);
+            // Below 0.975 the rounding error of `s` matters: as in musl,
+            // f + c = sqrt(z), where `f` is `s` with the low word cleared,
+            // so that `f * f` is exact.
+            let f: $uid = unsafe { transmute(s) };
+            let f: $id =
+                unsafe { transmute(f & $uid::splat(0xffff_ffff_0000_0000)) };
+            let c = (z - f * f) / (s + f);
+            let pio4_hi = $id::splat(0.5) * pio2_hi;
+            let mid = pio4_hi
+                - (two * s * r - (pio2_lo - two * c) - (pio4_hi - two * f));
+            a.ge($id::splat(0.975)).select(near_one, mid)
+        });
+    };
+}
+
+define_asin!(f32 => asin_v2f32, f32x2, u32x2);
+define_asin!(f32 => asin_v4f32, f32x4, u32x4);
+define_asin!(f32 => asin_v8f32, f32x8, u32x8);
+define_asin!(f32 => asin_v16f32, f32x16, u32x16);
+
+define_asin!(f64 => asin_v2f64, f64x2, u64x2);
+define_asin!(f64 => asin_v4f64, f64x4, u64x4);
+define_asin!(f64 => asin_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Asin, asin);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_asinf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_asind4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_asinf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_asinf8_u10avx2);
+                impl_unary!(f64x2: Sleef_asind2_u10avx2128);
+                impl_unary!(f64x4: Sleef_asind4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_asinf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_asind4_u10avx);
+
+                impl_unary!(f32x4: Sleef_asinf4_u10sse4);
+                impl_unary!(f32x8: Sleef_asinf8_u10avx);
+                impl_unary!(f64x2: Sleef_asind2_u10sse4);
+                impl_unary!(f64x4: Sleef_asind4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_asinf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_asind2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_asinf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_asinf4_u10sse4);
+                impl_unary!(f64x2: Sleef_asind2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_asind2_u10sse4);
+            } else {
+                impl_unary!(f32x2: asin_v2f32);
+                impl_unary!(f32x4: asin_v4f32);
+                impl_unary!(f32x8: asin_v8f32);
+                impl_unary!(f32x16: asin_v16f32);
+
+                impl_unary!(f64x2: asin_v2f64);
+                impl_unary!(f64x4: asin_v4f64);
+                impl_unary!(f64x8: asin_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: asin_v2f32);
+        impl_unary!(f32x4: asin_v4f32);
+        impl_unary!(f32x8: asin_v8f32);
+        impl_unary!(f32x16: asin_v16f32);
+
+        impl_unary!(f64x2: asin_v2f64);
+        impl_unary!(f64x4: asin_v4f64);
+        impl_unary!(f64x8: asin_v8f64);
+    }
+}
@@ -0,0 +1,110 @@
+//! Vertical floating-point `asinh`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+use super::ln_1p::Ln1p;
+
+crate trait Asinh {
+    fn asinh(self) -> Self;
+}
+
+macro_rules! define_asinh {
+    ($name:ident, $id:ident, $uid:ident, $big:expr, $ln_2:expr) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let one = $id::splat(1.);
+            let two = $id::splat(2.);
+            let a = x.abs();
+            let z = a * a;
+            // |x| < 2: ln_1p(|x| + x^2 / (sqrt(x^2 + 1) + 1))
+            let small = Ln1p::ln_1p(a + z / ((z + one).sqrt() + one));
+            // |x| < big: ln(2|x| + 1 / (sqrt(x^2 + 1) + |x|))
+            let mid = (two * a + one / ((z + one).sqrt() + a)).ln();
+            // otherwise x^2 + 1 == x^2 and asinh(|x|) = ln(2|x|):
+            let large = a.ln() + $id::splat($ln_2);
+            let r = a
+                .lt(two)
+                .select(small, a.lt($id::splat($big)).select(mid, large));
+            // asinh is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_asinh!($name, $id, $uid, 4096., crate::f32::consts::LN_2);
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_asinh!($name, $id, $uid, 67_108_864., crate::f64::consts::LN_2);
+    };
+}
+
+define_asinh!(f32 => asinh_v2f32, f32x2, u32x2);
+define_asinh!(f32 => asinh_v4f32, f32x4, u32x4);
+define_asinh!(f32 => asinh_v8f32, f32x8, u32x8);
+define_asinh!(f32 => asinh_v16f32, f32x16, u32x16);
+
+define_asinh!(f64 => asinh_v2f64, f64x2, u64x2);
+define_asinh!(f64 => asinh_v4f64, f64x4, u64x4);
+define_asinh!(f64 => asinh_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Asinh, asinh);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinhf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_asinhf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_asinhd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_asinhf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_asinhf8_u10avx2);
+                impl_unary!(f64x2: Sleef_asinhd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_asinhd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinhf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_asinhf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_asinhd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_asinhf4_u10sse4);
+                impl_unary!(f32x8: Sleef_asinhf8_u10avx);
+                impl_unary!(f64x2: Sleef_asinhd2_u10sse4);
+                impl_unary!(f64x4: Sleef_asinhd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_asinhf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_asinhf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_asinhd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_asinhf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_asinhf4_u10sse4);
+                impl_unary!(f64x2: Sleef_asinhd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_asinhd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: asinh_v2f32);
+                impl_unary!(f32x4: asinh_v4f32);
+                impl_unary!(f32x8: asinh_v8f32);
+                impl_unary!(f32x16: asinh_v16f32);
+
+                impl_unary!(f64x2: asinh_v2f64);
+                impl_unary!(f64x4: asinh_v4f64);
+                impl_unary!(f64x8: asinh_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: asinh_v2f32);
+        impl_unary!(f32x4: asinh_v4f32);
+        impl_unary!(f32x8: asinh_v8f32);
+        impl_unary!(f32x16: asinh_v16f32);
+
+        impl_unary!(f64x2: asinh_v2f64);
+        impl_unary!(f64x4: asinh_v4f64);
+        impl_unary!(f64x8: asinh_v8f64);
+    }
+}
@@ -0,0 +1,161 @@
+//! Vertical floating-point `atan`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+crate trait Atan {
+    fn atan(self) -> Self;
+}
+
+// Portable implementation following musl's `atanf.c` and `atan.c`: the
+// argument is reduced to `|t| < 0.4375` using one of
+// `atan(a) = atan(c) + atan((a - c) / (1 + a * c))` with
+// `c = 0.5, 1, 1.5, inf` depending on the magnitude of `a = |x|`, and
+// `atan(t)` is approximated by an odd polynomial.
+macro_rules! define_atan {
+    ($name:ident, $id:ident, $uid:ident, $poly:expr,
+     [$hi0:expr, $hi1:expr, $hi2:expr, $hi3:expr],
+     [$lo0:expr, $lo1:expr, $lo2:expr, $lo3:expr]) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let z = $id::splat(0.);
+            let one = $id::splat(1.);
+            let a = x.abs();
+            let m0 = a.lt($id::splat(0.4375));
+            let m1 = a.lt($id::splat(0.6875));
+            let m2 = a.lt($id::splat(1.1875));
+            let m3 = a.lt($id::splat(2.4375));
+            let t3 = -one / a;
+            let t2 = (a - $id::splat(1.5)) / (one + $id::splat(1.5) * a);
+            let t1 = (a - one) / (a + one);
+            let t0 = (a + a - one) / ($id::splat(2.) + a);
+            let t = m3.select(m2.select(m1.select(m0.select(a, t0), t1), t2), t3);
+            let hi = m3.select(
+                m2.select(
+                    m1.select(m0.select(z, $id::splat($hi0)), $id::splat($hi1)),
+                    $id::splat($hi2)),
+                $id::splat($hi3));
+            let lo = m3.select(
+                m2.select(
+                    m1.select(m0.select(z, $id::splat($lo0)), $id::splat($lo1)),
+                    $id::splat($lo2)),
+                $id::splat($lo3));
+            let r = hi - ((t * $poly(t * t) - lo) - t);
+            // atan is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_atan!(
+            $name, $id, $uid,
+            |z: $id| {
+                let w = z * z;
+                let s1 = z * ($id::splat(3.333_332_836_6e-01)
+                    + w * ($id::splat(1.425_363_570_5e-01)
+                    + w * $id::splat(6.168_760_731_8e-02)));
+                let s2 = w * ($id::splat(-1.999_915_838_2e-01)
+                    + w * $id::splat(-1.064_801_737_7e-01));
+                s1 + s2
+            },
+            [4.636_476_039_9e-01, 7.853_981_256_5e-01,
+             9.827_936_887_7e-01, 1.570_796_251_3e+00],
+            [5.012_158_244_0e-09, 3.774_894_707_9e-08,
+             3.447_321_717_0e-08, 7.549_789_415_9e-08]
+        );
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_atan!(
+            $name, $id, $uid,
+            |z: $id| {
+                let w = z * z;
+                let s1 = z * ($id::splat(3.333_333_333_333_293_180_27e-01)
+                    + w * ($id::splat(1.428_571_427_250_346_637_11e-01)
+                    + w * ($id::splat(9.090_887_133_436_506_561_96e-02)
+                    + w * ($id::splat(6.661_073_137_387_531_206_69e-02)
+                    + w * ($id::splat(4.976_877_994_615_932_360_17e-02)
+                    + w * $id::splat(1.628_582_011_536_578_236_23e-02))))));
+                let s2 = w * ($id::splat(-1.999_999_999_987_648_324_76e-01)
+                    + w * ($id::splat(-1.111_111_040_546_235_578_80e-01)
+                    + w * ($id::splat(-7.691_876_205_044_829_994_95e-02)
+                    + w * ($id::splat(-5.833_570_133_790_573_486_45e-02)
+                    + w * $id::splat(-3.653_157_274_421_691_552_70e-02)))));
+                s1 + s2
+            },
+            [4.636_476_090_008_060_935_15e-01, 7.853_981_633_974_482_789_99e-01,
+             9.827_937_232_473_290_540_82e-01, 1.570_796_326_794_896_558_00e+00],
+            [2.269_877_745_296_168_709_24e-17, 3.061_616_997_868_383_017_93e-17,
+             1.390_331_103_123_099_845_16e-17, 6.123_233_995_736_766_035_87e-17]
+        );
+    };
+}
+
+define_atan!(f32 => atan_v2f32, f32x2, u32x2);
+define_atan!(f32 => atan_v4f32, f32x4, u32x4);
+define_atan!(f32 => atan_v8f32, f32x8, u32x8);
+define_atan!(f32 => atan_v16f32, f32x16, u32x16);
+
+define_atan!(f64 => atan_v2f64, f64x2, u64x2);
+define_atan!(f64 => atan_v4f64, f64x4, u64x4);
+define_atan!(f64 => atan_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Atan, atan);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_atanf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_atand4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_atanf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_atanf8_u10avx2);
+                impl_unary!(f64x2: Sleef_atand2_u10avx2128);
+                impl_unary!(f64x4: Sleef_atand4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_atanf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_atand4_u10avx);
+
+                impl_unary!(f32x4: Sleef_atanf4_u10sse4);
+                impl_unary!(f32x8: Sleef_atanf8_u10avx);
+                impl_unary!(f64x2: Sleef_atand2_u10sse4);
+                impl_unary!(f64x4: Sleef_atand4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_atanf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_atand2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_atanf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_atanf4_u10sse4);
+                impl_unary!(f64x2: Sleef_atand2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_atand2_u10sse4);
+            } else {
+                impl_unary!(f32x2: atan_v2f32);
+                impl_unary!(f32x4: atan_v4f32);
+                impl_unary!(f32x8: atan_v8f32);
+                impl_unary!(f32x16: atan_v16f32);
+
+                impl_unary!(f64x2: atan_v2f64);
+                impl_unary!(f64x4: atan_v4f64);
+                impl_unary!(f64x8: atan_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: atan_v2f32);
+        impl_unary!(f32x4: atan_v4f32);
+        impl_unary!(f32x8: atan_v8f32);
+        impl_unary!(f32x16: atan_v16f32);
+
+        impl_unary!(f64x2: atan_v2f64);
+        impl_unary!(f64x4: atan_v4f64);
+        impl_unary!(f64x8: atan_v8f64);
+    }
+}
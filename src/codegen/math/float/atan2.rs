@@ -0,0 +1,132 @@
+//! Vertical floating-point `atan2`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+use super::atan::Atan;
+
+crate trait Atan2 {
+    fn atan2(self, x: Self) -> Self;
+}
+
+// Portable implementation: computes `atan(min(|y|, |x|) / max(|y|, |x|))`,
+// whose argument is in `[0, 1]`, and maps the result to the right octant.
+macro_rules! define_atan2 {
+    ($name:ident, $id:ident, $uid:ident,
+     $pio2_hi:expr, $pio2_lo:expr, $pi_hi:expr, $pi_lo:expr) => {
+        fn $name(y: $id, x: $id) -> $id {
+            use crate::mem::transmute;
+            let z = $id::splat(0.);
+            let inf = $id::INFINITY;
+            let ax = x.abs();
+            let ay = y.abs();
+            let swap = ay.gt(ax);
+            let num = swap.select(ax, ay);
+            let den = swap.select(ay, ax);
+            let t = num / den;
+            // atan2(+-0, +-0) and atan2(+-inf, +-inf) are not NaN:
+            let t = (num.eq(z) & den.eq(z)).select(z, t);
+            let t = (num.eq(inf) & den.eq(inf)).select($id::splat(1.), t);
+            let r = Atan::atan(t);
+            // |y| > |x|: atan(|y| / |x|) = pi/2 - atan(|x| / |y|)
+            let r = swap.select(
+                $id::splat($pio2_hi) - (r - $id::splat($pio2_lo)),
+                r,
+            );
+            // x < 0 (including x = -0): the result is pi - atan(|y / x|)
+            let xs: $uid = unsafe { transmute(x) };
+            let sign_mask = $uid::splat(!(!0 >> 1));
+            let r = (xs & sign_mask).ne($uid::splat(0)).select(
+                $id::splat($pi_hi) - (r - $id::splat($pi_lo)),
+                r,
+            );
+            // atan2 is odd in y:
+            let ys: $uid = unsafe { transmute(y) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (ys & sign_mask)) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_atan2!(
+            $name, $id, $uid,
+            1.570_796_251_3e+00, 7.549_789_415_9e-08,
+            3.141_592_741_0e+00, -8.742_277_657_3e-08
+        );
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_atan2!(
+            $name, $id, $uid,
+            1.570_796_326_794_896_558_00e+00, 6.123_233_995_736_766_035_87e-17,
+            3.141_592_653_589_793_116_00e+00, 1.224_646_799_147_353_177_20e-16
+        );
+    };
+}
+
+define_atan2!(f32 => atan2_v2f32, f32x2, u32x2);
+define_atan2!(f32 => atan2_v4f32, f32x4, u32x4);
+define_atan2!(f32 => atan2_v8f32, f32x8, u32x8);
+define_atan2!(f32 => atan2_v16f32, f32x16, u32x16);
+
+define_atan2!(f64 => atan2_v2f64, f64x2, u64x2);
+define_atan2!(f64 => atan2_v4f64, f64x4, u64x4);
+define_atan2!(f64 => atan2_v8f64, f64x8, u64x8);
+
+gen_binary_impl_table!(Atan2, atan2);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_atan2f4_u10avx2128);
+                impl_binary!(f32x16[h => f32x8]: Sleef_atan2f8_u10avx2);
+                impl_binary!(f64x8[h => f64x4]: Sleef_atan2d4_u10avx2);
+
+                impl_binary!(f32x4: Sleef_atan2f4_u10avx2128);
+                impl_binary!(f32x8: Sleef_atan2f8_u10avx2);
+                impl_binary!(f64x2: Sleef_atan2d2_u10avx2128);
+                impl_binary!(f64x4: Sleef_atan2d4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_atan2f4_u10sse4);
+                impl_binary!(f32x16[h => f32x8]: Sleef_atan2f8_u10avx);
+                impl_binary!(f64x8[h => f64x4]: Sleef_atan2d4_u10avx);
+
+                impl_binary!(f32x4: Sleef_atan2f4_u10sse4);
+                impl_binary!(f32x8: Sleef_atan2f8_u10avx);
+                impl_binary!(f64x2: Sleef_atan2d2_u10sse4);
+                impl_binary!(f64x4: Sleef_atan2d4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_atan2f4_u10sse4);
+                impl_binary!(f32x16[q => f32x4]: Sleef_atan2f4_u10sse4);
+                impl_binary!(f64x8[q => f64x2]: Sleef_atan2d2_u10sse4);
+
+                impl_binary!(f32x4: Sleef_atan2f4_u10sse4);
+                impl_binary!(f32x8[h => f32x4]: Sleef_atan2f4_u10sse4);
+                impl_binary!(f64x2: Sleef_atan2d2_u10sse4);
+                impl_binary!(f64x4[h => f64x2]: Sleef_atan2d2_u10sse4);
+            } else {
+                impl_binary!(f32x2: atan2_v2f32);
+                impl_binary!(f32x4: atan2_v4f32);
+                impl_binary!(f32x8: atan2_v8f32);
+                impl_binary!(f32x16: atan2_v16f32);
+
+                impl_binary!(f64x2: atan2_v2f64);
+                impl_binary!(f64x4: atan2_v4f64);
+                impl_binary!(f64x8: atan2_v8f64);
+            }
+        }
+    } else {
+        impl_binary!(f32x2: atan2_v2f32);
+        impl_binary!(f32x4: atan2_v4f32);
+        impl_binary!(f32x8: atan2_v8f32);
+        impl_binary!(f32x16: atan2_v16f32);
+
+        impl_binary!(f64x2: atan2_v2f64);
+        impl_binary!(f64x4: atan2_v4f64);
+        impl_binary!(f64x8: atan2_v8f64);
+    }
+}
@@ -0,0 +1,110 @@
+//! Vertical floating-point `atanh`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+use super::ln_1p::Ln1p;
+
+crate trait Atanh {
+    fn atanh(self) -> Self;
+}
+
+macro_rules! define_atanh {
+    ($name:ident, $id:ident, $uid:ident) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let half = $id::splat(0.5);
+            let one = $id::splat(1.);
+            let two = $id::splat(2.);
+            let a = x.abs();
+            // atanh(|x|) = ln_1p(2|x| / (1 - |x|)) / 2, where for |x| < 0.5
+            // the argument is rewritten as 2|x| + 2|x|^2 / (1 - |x|) to
+            // avoid losing the low bits of |x|. |x| == 1 produces an
+            // infinity and |x| > 1 a NaN.
+            let w = a.lt(half).select(
+                two * a + two * a * a / (one - a),
+                two * (a / (one - a)),
+            );
+            let r = half * Ln1p::ln_1p(w);
+            // atanh is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_atanh!($name, $id, $uid);
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_atanh!($name, $id, $uid);
+    };
+}
+
+define_atanh!(f32 => atanh_v2f32, f32x2, u32x2);
+define_atanh!(f32 => atanh_v4f32, f32x4, u32x4);
+define_atanh!(f32 => atanh_v8f32, f32x8, u32x8);
+define_atanh!(f32 => atanh_v16f32, f32x16, u32x16);
+
+define_atanh!(f64 => atanh_v2f64, f64x2, u64x2);
+define_atanh!(f64 => atanh_v4f64, f64x4, u64x4);
+define_atanh!(f64 => atanh_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Atanh, atanh);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanhf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_atanhf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_atanhd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_atanhf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_atanhf8_u10avx2);
+                impl_unary!(f64x2: Sleef_atanhd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_atanhd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanhf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_atanhf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_atanhd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_atanhf4_u10sse4);
+                impl_unary!(f32x8: Sleef_atanhf8_u10avx);
+                impl_unary!(f64x2: Sleef_atanhd2_u10sse4);
+                impl_unary!(f64x4: Sleef_atanhd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_atanhf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_atanhf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_atanhd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_atanhf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_atanhf4_u10sse4);
+                impl_unary!(f64x2: Sleef_atanhd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_atanhd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: atanh_v2f32);
+                impl_unary!(f32x4: atanh_v4f32);
+                impl_unary!(f32x8: atanh_v8f32);
+                impl_unary!(f32x16: atanh_v16f32);
+
+                impl_unary!(f64x2: atanh_v2f64);
+                impl_unary!(f64x4: atanh_v4f64);
+                impl_unary!(f64x8: atanh_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: atanh_v2f32);
+        impl_unary!(f32x4: atanh_v4f32);
+        impl_unary!(f32x8: atanh_v8f32);
+        impl_unary!(f32x16: atanh_v16f32);
+
+        impl_unary!(f64x2: atanh_v2f64);
+        impl_unary!(f64x4: atanh_v4f64);
+        impl_unary!(f64x8: atanh_v8f64);
+    }
+}
@@ -1,55 +1,56 @@
 //! Vertical floating-point `cos`
 #![allow(unused)]
 
-// FIXME 64-bit 1 elem vector cos
-
 use crate::*;
 
+use super::sin::SinCosK;
+
 crate trait Cos {
     fn cos(self) -> Self;
 }
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.cos.v2f32"]
-    fn cos_v2f32(x: f32x2) -> f32x2;
-    #[link_name = "llvm.cos.v4f32"]
-    fn cos_v4f32(x: f32x4) -> f32x4;
-    #[link_name = "llvm.cos.v8f32"]
-    fn cos_v8f32(x: f32x8) -> f32x8;
-    #[link_name = "llvm.cos.v16f32"]
-    fn cos_v16f32(x: f32x16) -> f32x16;
-    /* FIXME 64-bit cosgle elem vectors
-    #[link_name = "llvm.cos.v1f64"]
-    fn cos_v1f64(x: f64x1) -> f64x1;
-     */
-    #[link_name = "llvm.cos.v2f64"]
-    fn cos_v2f64(x: f64x2) -> f64x2;
-    #[link_name = "llvm.cos.v4f64"]
-    fn cos_v4f64(x: f64x4) -> f64x4;
-    #[link_name = "llvm.cos.v8f64"]
-    fn cos_v8f64(x: f64x8) -> f64x8;
-
-    #[link_name = "llvm.cos.f32"]
-    fn cos_f32(x: f32) -> f32;
-    #[link_name = "llvm.cos.f64"]
-    fn cos_f64(x: f64) -> f64;
+// Lanes out of the range of `SinCosK` are rare, and computed with `libm`.
+macro_rules! define_cos {
+    ($name:ident, $id:ident, $libm:path) => {
+        fn $name(x: $id) -> $id {
+            let (_, c) = SinCosK::sin_cos_k(x);
+            let huge = x.abs().gt($id::SIN_COS_K_MAX);
+            if !huge.any() {
+                return c;
+            }
+            let mut c = c;
+            for i in 0..$id::lanes() {
+                if huge.extract(i) {
+                    c = c.replace(i, $libm(x.extract(i)));
+                }
+            }
+            c
+        }
+    };
+    (f32 => $name:ident, $id:ident) => {
+        define_cos!($name, $id, libm::cosf);
+    };
+    (f64 => $name:ident, $id:ident) => {
+        define_cos!($name, $id, libm::cos);
+    };
 }
 
+define_cos!(f32 => cos_v2f32, f32x2);
+define_cos!(f32 => cos_v4f32, f32x4);
+define_cos!(f32 => cos_v8f32, f32x8);
+
+define_cos!(f64 => cos_v2f64, f64x2);
+define_cos!(f64 => cos_v4f64, f64x4);
+define_cos!(f64 => cos_v8f64, f64x8);
+
 gen_unary_impl_table!(Cos, cos);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_unary!(f32x2[f32; 2]: cos_f32);
-        impl_unary!(f32x4[f32; 4]: cos_f32);
-        impl_unary!(f32x8[f32; 8]: cos_f32);
-        impl_unary!(f32x16[f32; 16]: cos_f32);
-
-        impl_unary!(f64x2[f64; 2]: cos_f64);
-        impl_unary!(f64x4[f64; 4]: cos_f64);
-        impl_unary!(f64x8[f64; 8]: cos_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -80,21 +81,21 @@ cfg_if! {
                 impl_unary!(f64x2: Sleef_cosd2_u10sse4);
                 impl_unary!(f64x4[h => f64x2]: Sleef_cosd2_u10sse4);
             } else {
-                impl_unary!(f32x2[f32; 2]: cos_f32);
-                impl_unary!(f32x16: cos_v16f32);
-                impl_unary!(f64x8: cos_v8f64);
-
+                impl_unary!(f32x2: cos_v2f32);
                 impl_unary!(f32x4: cos_v4f32);
                 impl_unary!(f32x8: cos_v8f32);
+                impl_unary!(f32x16[h => f32x8]: cos_v8f32);
+
                 impl_unary!(f64x2: cos_v2f64);
                 impl_unary!(f64x4: cos_v4f64);
+                impl_unary!(f64x8: cos_v8f64);
             }
         }
     } else {
-        impl_unary!(f32x2[f32; 2]: cos_f32);
+        impl_unary!(f32x2: cos_v2f32);
         impl_unary!(f32x4: cos_v4f32);
         impl_unary!(f32x8: cos_v8f32);
-        impl_unary!(f32x16: cos_v16f32);
+        impl_unary!(f32x16[h => f32x8]: cos_v8f32);
 
         impl_unary!(f64x2: cos_v2f64);
         impl_unary!(f64x4: cos_v4f64);
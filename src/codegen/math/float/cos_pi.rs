@@ -33,7 +33,11 @@ macro_rules! impl_def64 {
 }
 
 cfg_if! {
-    if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
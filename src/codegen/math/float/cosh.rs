@@ -0,0 +1,99 @@
+//! Vertical floating-point `cosh`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+crate trait Cosh {
+    fn cosh(self) -> Self;
+}
+
+macro_rules! define_cosh {
+    ($name:ident, $id:ident, $ln_max:expr) => {
+        fn $name(x: $id) -> $id {
+            let half = $id::splat(0.5);
+            let a = x.abs();
+            // cosh(x) = (e^|x| + e^-|x|) / 2
+            //
+            // Close to overflow e^|x| is not representable but cosh(x)
+            // might be, so it is computed as (e^(|x| / 2) / 2) * e^(|x| / 2).
+            let huge = a.gt($id::splat($ln_max));
+            let e = huge.select(half * a, a).exp();
+            huge.select((half * e) * e, half * e + half / e)
+        }
+    };
+    (f32 => $name:ident, $id:ident) => {
+        define_cosh!($name, $id, 88.722_83);
+    };
+    (f64 => $name:ident, $id:ident) => {
+        define_cosh!($name, $id, 709.78);
+    };
+}
+
+define_cosh!(f32 => cosh_v2f32, f32x2);
+define_cosh!(f32 => cosh_v4f32, f32x4);
+define_cosh!(f32 => cosh_v8f32, f32x8);
+define_cosh!(f32 => cosh_v16f32, f32x16);
+
+define_cosh!(f64 => cosh_v2f64, f64x2);
+define_cosh!(f64 => cosh_v4f64, f64x4);
+define_cosh!(f64 => cosh_v8f64, f64x8);
+
+gen_unary_impl_table!(Cosh, cosh);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_coshf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_coshf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_coshd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_coshf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_coshf8_u10avx2);
+                impl_unary!(f64x2: Sleef_coshd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_coshd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_coshf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_coshf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_coshd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_coshf4_u10sse4);
+                impl_unary!(f32x8: Sleef_coshf8_u10avx);
+                impl_unary!(f64x2: Sleef_coshd2_u10sse4);
+                impl_unary!(f64x4: Sleef_coshd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_coshf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_coshf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_coshd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_coshf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_coshf4_u10sse4);
+                impl_unary!(f64x2: Sleef_coshd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_coshd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: cosh_v2f32);
+                impl_unary!(f32x4: cosh_v4f32);
+                impl_unary!(f32x8: cosh_v8f32);
+                impl_unary!(f32x16: cosh_v16f32);
+
+                impl_unary!(f64x2: cosh_v2f64);
+                impl_unary!(f64x4: cosh_v4f64);
+                impl_unary!(f64x8: cosh_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: cosh_v2f32);
+        impl_unary!(f32x4: cosh_v4f32);
+        impl_unary!(f32x8: cosh_v8f32);
+        impl_unary!(f32x16: cosh_v16f32);
+
+        impl_unary!(f64x2: cosh_v2f64);
+        impl_unary!(f64x4: cosh_v4f64);
+        impl_unary!(f64x8: cosh_v8f64);
+    }
+}
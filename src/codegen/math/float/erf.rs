@@ -0,0 +1,78 @@
+//! Vertical floating-point `erf`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Erf {
+    fn erf(self) -> Self;
+}
+
+// LLVM does not have an intrinsic for `erf`; without SLEEF we call the
+// `libm` version on each lane so that the results match the scalar ones.
+fn erf_f32(x: f32) -> f32 {
+    libm::erff(x)
+}
+
+fn erf_f64(x: f64) -> f64 {
+    libm::erf(x)
+}
+
+gen_unary_impl_table!(Erf, erf);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erff4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_erff8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_erfd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_erff4_u10avx2128);
+                impl_unary!(f32x8: Sleef_erff8_u10avx2);
+                impl_unary!(f64x2: Sleef_erfd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_erfd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erff4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_erff8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_erfd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_erff4_u10sse4);
+                impl_unary!(f32x8: Sleef_erff8_u10avx);
+                impl_unary!(f64x2: Sleef_erfd2_u10sse4);
+                impl_unary!(f64x4: Sleef_erfd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erff4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_erff4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_erfd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_erff4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_erff4_u10sse4);
+                impl_unary!(f64x2: Sleef_erfd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_erfd2_u10sse4);
+            } else {
+                impl_unary!(f32x2[f32; 2]: erf_f32);
+                impl_unary!(f32x4[f32; 4]: erf_f32);
+                impl_unary!(f32x8[f32; 8]: erf_f32);
+                impl_unary!(f32x16[f32; 16]: erf_f32);
+
+                impl_unary!(f64x2[f64; 2]: erf_f64);
+                impl_unary!(f64x4[f64; 4]: erf_f64);
+                impl_unary!(f64x8[f64; 8]: erf_f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2[f32; 2]: erf_f32);
+        impl_unary!(f32x4[f32; 4]: erf_f32);
+        impl_unary!(f32x8[f32; 8]: erf_f32);
+        impl_unary!(f32x16[f32; 16]: erf_f32);
+
+        impl_unary!(f64x2[f64; 2]: erf_f64);
+        impl_unary!(f64x4[f64; 4]: erf_f64);
+        impl_unary!(f64x8[f64; 8]: erf_f64);
+    }
+}
@@ -0,0 +1,78 @@
+//! Vertical floating-point `erfc`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Erfc {
+    fn erfc(self) -> Self;
+}
+
+// LLVM does not have an intrinsic for `erfc`; without SLEEF we call the
+// `libm` version on each lane so that the results match the scalar ones.
+fn erfc_f32(x: f32) -> f32 {
+    libm::erfcf(x)
+}
+
+fn erfc_f64(x: f64) -> f64 {
+    libm::erfc(x)
+}
+
+gen_unary_impl_table!(Erfc, erfc);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erfcf4_u15avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_erfcf8_u15avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_erfcd4_u15avx2);
+
+                impl_unary!(f32x4: Sleef_erfcf4_u15avx2128);
+                impl_unary!(f32x8: Sleef_erfcf8_u15avx2);
+                impl_unary!(f64x2: Sleef_erfcd2_u15avx2128);
+                impl_unary!(f64x4: Sleef_erfcd4_u15avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erfcf4_u15sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_erfcf8_u15avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_erfcd4_u15avx);
+
+                impl_unary!(f32x4: Sleef_erfcf4_u15sse4);
+                impl_unary!(f32x8: Sleef_erfcf8_u15avx);
+                impl_unary!(f64x2: Sleef_erfcd2_u15sse4);
+                impl_unary!(f64x4: Sleef_erfcd4_u15avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_erfcf4_u15sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_erfcf4_u15sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_erfcd2_u15sse4);
+
+                impl_unary!(f32x4: Sleef_erfcf4_u15sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_erfcf4_u15sse4);
+                impl_unary!(f64x2: Sleef_erfcd2_u15sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_erfcd2_u15sse4);
+            } else {
+                impl_unary!(f32x2[f32; 2]: erfc_f32);
+                impl_unary!(f32x4[f32; 4]: erfc_f32);
+                impl_unary!(f32x8[f32; 8]: erfc_f32);
+                impl_unary!(f32x16[f32; 16]: erfc_f32);
+
+                impl_unary!(f64x2[f64; 2]: erfc_f64);
+                impl_unary!(f64x4[f64; 4]: erfc_f64);
+                impl_unary!(f64x8[f64; 8]: erfc_f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2[f32; 2]: erfc_f32);
+        impl_unary!(f32x4[f32; 4]: erfc_f32);
+        impl_unary!(f32x8[f32; 8]: erfc_f32);
+        impl_unary!(f32x16[f32; 16]: erfc_f32);
+
+        impl_unary!(f64x2[f64; 2]: erfc_f64);
+        impl_unary!(f64x4[f64; 4]: erfc_f64);
+        impl_unary!(f64x8[f64; 8]: erfc_f64);
+    }
+}
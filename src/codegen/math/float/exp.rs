@@ -1,7 +1,5 @@
 //! Vertical floating-point `exp`
-#![allow(unused)]
-
-// FIXME 64-bit expgle elem vectors misexpg
+#![allow(unused, clippy::excessive_precision)]
 
 use crate::*;
 
@@ -9,47 +7,173 @@ crate trait Exp {
     fn exp(self) -> Self;
 }
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.exp.v2f32"]
-    fn exp_v2f32(x: f32x2) -> f32x2;
-    #[link_name = "llvm.exp.v4f32"]
-    fn exp_v4f32(x: f32x4) -> f32x4;
-    #[link_name = "llvm.exp.v8f32"]
-    fn exp_v8f32(x: f32x8) -> f32x8;
-    #[link_name = "llvm.exp.v16f32"]
-    fn exp_v16f32(x: f32x16) -> f32x16;
-    /* FIXME 64-bit expgle elem vectors
-    #[link_name = "llvm.exp.v1f64"]
-    fn exp_v1f64(x: f64x1) -> f64x1;
-     */
-    #[link_name = "llvm.exp.v2f64"]
-    fn exp_v2f64(x: f64x2) -> f64x2;
-    #[link_name = "llvm.exp.v4f64"]
-    fn exp_v4f64(x: f64x4) -> f64x4;
-    #[link_name = "llvm.exp.v8f64"]
-    fn exp_v8f64(x: f64x8) -> f64x8;
-
-    #[link_name = "llvm.exp.f32"]
-    fn exp_f32(x: f32) -> f32;
-    #[link_name = "llvm.exp.f64"]
-    fn exp_f64(x: f64) -> f64;
+/// `e^(self + lo)`, where `lo` is a correction much smaller than `self`.
+/// Shared by the portable `exp` and `powf`.
+///
+/// The argument is reduced to `r = self + lo - n * ln(2)` with
+/// `|r| <= ln(2) / 2`, `e^r` is approximated with the polynomial of Cephes'
+/// `expf` or the rational approximation of Cephes' `exp`, and the result
+/// is scaled by `2^n` in two steps so that subnormal results are not
+/// flushed to zero.
+crate trait ExpLo {
+    fn exp_lo(self, lo: Self) -> Self;
+}
+
+/// Like `ExpLo`, but with a cheaper approximation of `e^r`, shared by the
+/// portable `exp_fast`. For `f32` this is a degree 6 instead of a degree 7
+/// polynomial, which saves one multiply-add. For `f64` this is a degree 11
+/// minimax polynomial, which avoids the division of the rational
+/// approximation.
+crate trait ExpFastLo {
+    fn exp_fast_lo(self, lo: Self) -> Self;
+}
+
+macro_rules! impl_exp_lo {
+    (impl $trait:ident::$fun:ident for $id:ident, $iid:ident, $min:expr,
+     $max:expr, $toint:expr, $toint_bits:expr, $ln2_hi:expr, $ln2_lo:expr,
+     $mant:expr, $bias:expr, |$r:ident| $poly:expr) => {
+        impl $trait for $id {
+            #[inline]
+            fn $fun(self, lo: Self) -> Self {
+                use crate::mem::transmute;
+                // Out of this range the result is either zero or infinity.
+                // NaNs are propagated.
+                let x = self.lt($id::splat($min)).select($id::splat($min), self);
+                let x = x.gt($id::splat($max)).select($id::splat($max), x);
+
+                // n = round(x / ln(2)) is stored in the low bits of `t`:
+                let toint = $id::splat($toint);
+                let t = x * $id::splat(crate::f64::consts::LOG2_E as _) + toint;
+                let fnn = t - toint;
+                let n: $iid = unsafe { transmute(t) };
+                let n = n - $iid::splat($toint_bits);
+
+                let $r = x - fnn * $id::splat($ln2_hi);
+                let $r = $r - fnn * $id::splat($ln2_lo);
+                let $r = $r + lo;
+                let y = $poly;
+
+                let n1 = n >> 1;
+                let n2 = n - n1;
+                let s1: $id = unsafe { transmute((n1 + $iid::splat($bias)) << $mant) };
+                let s2: $id = unsafe { transmute((n2 + $iid::splat($bias)) << $mant) };
+                (y * s1) * s2
+            }
+        }
+    };
+    (f32: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_exp_lo!(
+                impl ExpLo::exp_lo for $id, $iid,
+                -104., 89., 12_582_912., 0x4b40_0000,
+                0.693_359_375, -2.121_944_40e-4, 23, 127,
+                |r| {
+                    let z = r * r;
+                    let p = $id::splat(1.987_569_150_0e-4);
+                    let p = p * r + $id::splat(1.398_199_950_7e-3);
+                    let p = p * r + $id::splat(8.333_451_907_3e-3);
+                    let p = p * r + $id::splat(4.166_579_589_4e-2);
+                    let p = p * r + $id::splat(1.666_666_545_9e-1);
+                    let p = p * r + $id::splat(5.000_000_120_1e-1);
+                    p * z + r + $id::splat(1.)
+                }
+            );
+        )*
+    };
+    (f64: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_exp_lo!(
+                impl ExpLo::exp_lo for $id, $iid,
+                -746., 710., 6_755_399_441_055_744.,
+                0x4338_0000_0000_0000, 6.931_457_519_531_25e-1,
+                1.428_606_820_309_417_232_12e-6, 52, 1023,
+                |r| {
+                    let xx = r * r;
+                    let p = $id::splat(1.261_771_930_748_105_908_78e-4);
+                    let p = p * xx + $id::splat(3.029_944_077_074_419_613_00e-2);
+                    let p = p * xx + $id::splat(9.999_999_999_999_999_999_10e-1);
+                    let px = r * p;
+                    let q = $id::splat(3.001_985_051_386_644_550_42e-6);
+                    let q = q * xx + $id::splat(2.524_483_403_496_841_041_92e-3);
+                    let q = q * xx + $id::splat(2.272_655_482_081_550_287_66e-1);
+                    let q = q * xx + $id::splat(2.000_000_000_000_000_000_09e0);
+                    $id::splat(1.) + $id::splat(2.) * (px / (q - px))
+                }
+            );
+        )*
+    };
+    (f32 fast: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_exp_lo!(
+                impl ExpFastLo::exp_fast_lo for $id, $iid,
+                -104., 89., 12_582_912., 0x4b40_0000,
+                0.693_359_375, -2.121_944_40e-4, 23, 127,
+                |r| {
+                    let p = $id::splat(1.392_617_611_993_557_888_1e-3);
+                    let p = p * r + $id::splat(8.363_173_074_513_710_900_8e-3);
+                    let p = p * r + $id::splat(4.166_655_466_205_053_398_2e-2);
+                    let p = p * r + $id::splat(1.666_657_702_559_798_819e-1);
+                    let p = p * r + $id::splat(0.5);
+                    p * (r * r) + r + $id::splat(1.)
+                }
+            );
+        )*
+    };
+    (f64 fast: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_exp_lo!(
+                impl ExpFastLo::exp_fast_lo for $id, $iid,
+                -746., 710., 6_755_399_441_055_744.,
+                0x4338_0000_0000_0000, 6.931_457_519_531_25e-1,
+                1.428_606_820_309_417_232_12e-6, 52, 1023,
+                |r| {
+                    let p = $id::splat(2.510_037_583_256_123_366_1e-8);
+                    let p = p * r + $id::splat(2.762_007_587_998_336_658_2e-7);
+                    let p = p * r + $id::splat(2.755_726_848_031_002_552_6e-6);
+                    let p = p * r + $id::splat(2.480_152_132_236_869_326_6e-5);
+                    let p = p * r + $id::splat(1.984_126_986_304_054_527_1e-4);
+                    let p = p * r + $id::splat(1.388_888_891_719_671_907_7e-3);
+                    let p = p * r + $id::splat(8.333_333_333_330_064_449_5e-3);
+                    let p = p * r + $id::splat(4.166_666_666_662_416_190_3e-2);
+                    let p = p * r + $id::splat(1.666_666_666_666_666_745_2e-1);
+                    let p = p * r + $id::splat(5.000_000_000_000_001_021_1e-1);
+                    p * (r * r) + r + $id::splat(1.)
+                }
+            );
+        )*
+    };
+}
+
+impl_exp_lo!(f32: f32x2, i32x2; f32x4, i32x4; f32x8, i32x8; f32x16, i32x16);
+impl_exp_lo!(f64: f64x2, i64x2; f64x4, i64x4; f64x8, i64x8);
+impl_exp_lo!(f32 fast: f32x2, i32x2; f32x4, i32x4; f32x8, i32x8; f32x16, i32x16);
+impl_exp_lo!(f64 fast: f64x2, i64x2; f64x4, i64x4; f64x8, i64x8);
+
+macro_rules! define_exp {
+    ($name:ident, $id:ident) => {
+        fn $name(x: $id) -> $id {
+            ExpLo::exp_lo(x, $id::splat(0.))
+        }
+    };
 }
 
+define_exp!(exp_v2f32, f32x2);
+define_exp!(exp_v4f32, f32x4);
+define_exp!(exp_v8f32, f32x8);
+define_exp!(exp_v16f32, f32x16);
+
+define_exp!(exp_v2f64, f64x2);
+define_exp!(exp_v4f64, f64x4);
+define_exp!(exp_v8f64, f64x8);
+
 gen_unary_impl_table!(Exp, exp);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_unary!(f32x2[f32; 2]: exp_f32);
-        impl_unary!(f32x4[f32; 4]: exp_f32);
-        impl_unary!(f32x8[f32; 8]: exp_f32);
-        impl_unary!(f32x16[f32; 16]: exp_f32);
-
-        impl_unary!(f64x2[f64; 2]: exp_f64);
-        impl_unary!(f64x4[f64; 4]: exp_f64);
-        impl_unary!(f64x8[f64; 8]: exp_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -89,18 +213,18 @@ cfg_if! {
                 impl_unary!(f64x2: Sleef_expd2_u10sse2);
                 impl_unary!(f64x4[h => f64x2]: Sleef_expd2_u10sse2);
             } else {
-                impl_unary!(f32x2[f32; 2]: exp_f32);
-                impl_unary!(f32x16: exp_v16f32);
-                impl_unary!(f64x8: exp_v8f64);
-
+                impl_unary!(f32x2: exp_v2f32);
                 impl_unary!(f32x4: exp_v4f32);
                 impl_unary!(f32x8: exp_v8f32);
+                impl_unary!(f32x16: exp_v16f32);
+
                 impl_unary!(f64x2: exp_v2f64);
                 impl_unary!(f64x4: exp_v4f64);
+                impl_unary!(f64x8: exp_v8f64);
             }
         }
     } else {
-        impl_unary!(f32x2[f32; 2]: exp_f32);
+        impl_unary!(f32x2: exp_v2f32);
         impl_unary!(f32x4: exp_v4f32);
         impl_unary!(f32x8: exp_v8f32);
         impl_unary!(f32x16: exp_v16f32);
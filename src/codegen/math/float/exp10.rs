@@ -0,0 +1,119 @@
+//! Vertical floating-point `exp10`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Exp10 {
+    fn exp10(self) -> Self;
+}
+
+macro_rules! define_exp10 {
+    ($name:ident, $basetype:ty, $simdtype:ty, $lanes:expr, $trait:path) => {
+        fn $name(x: $simdtype) -> $simdtype {
+            use core::intrinsics::transmute;
+            let mut buf: [$basetype; $lanes] = unsafe { transmute(x) };
+            for elem in &mut buf {
+                *elem = <$basetype as $trait>::powf(10., *elem);
+            }
+            unsafe { transmute(buf) }
+        }
+    };
+
+    (f32 => $name:ident, $type:ty, $lanes:expr) => {
+        define_exp10!($name, f32, $type, $lanes, libm::F32Ext);
+    };
+
+    (f64 => $name:ident, $type:ty, $lanes:expr) => {
+        define_exp10!($name, f64, $type, $lanes, libm::F64Ext);
+    };
+}
+
+// LLVM does not have an intrinsic for `exp10`; we thus call the `libm`
+// `powf` with an exact base of `10` on each lane, which unlike
+// `exp2(x * log2(10))` does not lose accuracy for large `x`.
+define_exp10!(f32 => exp10_v2f32, f32x2, 2);
+define_exp10!(f32 => exp10_v4f32, f32x4, 4);
+define_exp10!(f32 => exp10_v8f32, f32x8, 8);
+define_exp10!(f32 => exp10_v16f32, f32x16, 16);
+
+define_exp10!(f64 => exp10_v2f64, f64x2, 2);
+define_exp10!(f64 => exp10_v4f64, f64x4, 4);
+define_exp10!(f64 => exp10_v8f64, f64x8, 8);
+
+fn exp10_f32(x: f32) -> f32 {
+    libm::F32Ext::powf(10., x)
+}
+
+fn exp10_f64(x: f64) -> f64 {
+    libm::F64Ext::powf(10., x)
+}
+
+gen_unary_impl_table!(Exp10, exp10);
+
+cfg_if! {
+    if #[cfg(target_arch = "s390x")] {
+        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+        impl_unary!(f32x2[f32; 2]: exp10_f32);
+        impl_unary!(f32x4[f32; 4]: exp10_f32);
+        impl_unary!(f32x8[f32; 8]: exp10_f32);
+        impl_unary!(f32x16[f32; 16]: exp10_f32);
+
+        impl_unary!(f64x2[f64; 2]: exp10_f64);
+        impl_unary!(f64x4[f64; 4]: exp10_f64);
+        impl_unary!(f64x8[f64; 8]: exp10_f64);
+    } else if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_exp10f4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_exp10f8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_exp10d4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_exp10f4_u10avx2128);
+                impl_unary!(f32x8: Sleef_exp10f8_u10avx2);
+                impl_unary!(f64x2: Sleef_exp10d2_u10avx2128);
+                impl_unary!(f64x4: Sleef_exp10d4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_exp10f4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_exp10f8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_exp10d4_u10avx);
+
+                impl_unary!(f32x4: Sleef_exp10f4_u10sse4);
+                impl_unary!(f32x8: Sleef_exp10f8_u10avx);
+                impl_unary!(f64x2: Sleef_exp10d2_u10sse4);
+                impl_unary!(f64x4: Sleef_exp10d4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_exp10f4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_exp10f4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_exp10d2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_exp10f4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_exp10f4_u10sse4);
+                impl_unary!(f64x2: Sleef_exp10d2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_exp10d2_u10sse4);
+            } else {
+                impl_unary!(f32x2[f32; 2]: exp10_f32);
+                impl_unary!(f32x16: exp10_v16f32);
+                impl_unary!(f64x8: exp10_v8f64);
+
+                impl_unary!(f32x4: exp10_v4f32);
+                impl_unary!(f32x8: exp10_v8f32);
+                impl_unary!(f64x2: exp10_v2f64);
+                impl_unary!(f64x4: exp10_v4f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2[f32; 2]: exp10_f32);
+        impl_unary!(f32x4: exp10_v4f32);
+        impl_unary!(f32x8: exp10_v8f32);
+        impl_unary!(f32x16: exp10_v16f32);
+
+        impl_unary!(f64x2: exp10_v2f64);
+        impl_unary!(f64x4: exp10_v4f64);
+        impl_unary!(f64x8: exp10_v8f64);
+    }
+}
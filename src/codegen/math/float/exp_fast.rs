@@ -0,0 +1,40 @@
+//! Vertical floating-point `exp_fast`
+#![allow(unused)]
+
+use crate::*;
+
+use super::exp::ExpFastLo;
+
+crate trait ExpFast {
+    fn exp_fast(self) -> Self;
+}
+
+macro_rules! define_exp_fast {
+    ($name:ident, $id:ident) => {
+        fn $name(x: $id) -> $id {
+            ExpFastLo::exp_fast_lo(x, $id::splat(0.))
+        }
+    };
+}
+
+define_exp_fast!(exp_fast_v2f32, f32x2);
+define_exp_fast!(exp_fast_v4f32, f32x4);
+define_exp_fast!(exp_fast_v8f32, f32x8);
+define_exp_fast!(exp_fast_v16f32, f32x16);
+
+define_exp_fast!(exp_fast_v2f64, f64x2);
+define_exp_fast!(exp_fast_v4f64, f64x4);
+define_exp_fast!(exp_fast_v8f64, f64x8);
+
+gen_unary_impl_table!(ExpFast, exp_fast);
+
+// SLEEF does not provide a less accurate `exp`.
+
+impl_unary!(f32x2: exp_fast_v2f32);
+impl_unary!(f32x4: exp_fast_v4f32);
+impl_unary!(f32x8: exp_fast_v8f32);
+impl_unary!(f32x16: exp_fast_v16f32);
+
+impl_unary!(f64x2: exp_fast_v2f64);
+impl_unary!(f64x4: exp_fast_v4f64);
+impl_unary!(f64x8: exp_fast_v8f64);
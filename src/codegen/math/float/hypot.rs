@@ -0,0 +1,78 @@
+//! Vertical floating-point `hypot`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Hypot {
+    fn hypot(self, y: Self) -> Self;
+}
+
+// LLVM does not have an intrinsic for `hypot`; without SLEEF we call the
+// `libm` version on each lane so that the results match the scalar ones.
+fn hypot_f32(x: f32, y: f32) -> f32 {
+    libm::hypotf(x, y)
+}
+
+fn hypot_f64(x: f64, y: f64) -> f64 {
+    libm::hypot(x, y)
+}
+
+gen_binary_impl_table!(Hypot, hypot);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_hypotf4_u05avx2128);
+                impl_binary!(f32x16[h => f32x8]: Sleef_hypotf8_u05avx2);
+                impl_binary!(f64x8[h => f64x4]: Sleef_hypotd4_u05avx2);
+
+                impl_binary!(f32x4: Sleef_hypotf4_u05avx2128);
+                impl_binary!(f32x8: Sleef_hypotf8_u05avx2);
+                impl_binary!(f64x2: Sleef_hypotd2_u05avx2128);
+                impl_binary!(f64x4: Sleef_hypotd4_u05avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_hypotf4_u05sse4);
+                impl_binary!(f32x16[h => f32x8]: Sleef_hypotf8_u05avx);
+                impl_binary!(f64x8[h => f64x4]: Sleef_hypotd4_u05avx);
+
+                impl_binary!(f32x4: Sleef_hypotf4_u05sse4);
+                impl_binary!(f32x8: Sleef_hypotf8_u05avx);
+                impl_binary!(f64x2: Sleef_hypotd2_u05sse4);
+                impl_binary!(f64x4: Sleef_hypotd4_u05avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_binary!(f32x2[t => f32x4]: Sleef_hypotf4_u05sse4);
+                impl_binary!(f32x16[q => f32x4]: Sleef_hypotf4_u05sse4);
+                impl_binary!(f64x8[q => f64x2]: Sleef_hypotd2_u05sse4);
+
+                impl_binary!(f32x4: Sleef_hypotf4_u05sse4);
+                impl_binary!(f32x8[h => f32x4]: Sleef_hypotf4_u05sse4);
+                impl_binary!(f64x2: Sleef_hypotd2_u05sse4);
+                impl_binary!(f64x4[h => f64x2]: Sleef_hypotd2_u05sse4);
+            } else {
+                impl_binary!(f32x2[f32; 2]: hypot_f32);
+                impl_binary!(f32x4[f32; 4]: hypot_f32);
+                impl_binary!(f32x8[f32; 8]: hypot_f32);
+                impl_binary!(f32x16[f32; 16]: hypot_f32);
+
+                impl_binary!(f64x2[f64; 2]: hypot_f64);
+                impl_binary!(f64x4[f64; 4]: hypot_f64);
+                impl_binary!(f64x8[f64; 8]: hypot_f64);
+            }
+        }
+    } else {
+        impl_binary!(f32x2[f32; 2]: hypot_f32);
+        impl_binary!(f32x4[f32; 4]: hypot_f32);
+        impl_binary!(f32x8[f32; 8]: hypot_f32);
+        impl_binary!(f32x16[f32; 16]: hypot_f32);
+
+        impl_binary!(f64x2[f64; 2]: hypot_f64);
+        impl_binary!(f64x4[f64; 4]: hypot_f64);
+        impl_binary!(f64x8[f64; 8]: hypot_f64);
+    }
+}
@@ -1,7 +1,5 @@
 //! Vertical floating-point `ln`
-#![allow(unused)]
-
-// FIXME 64-bit lngle elem vectors mislng
+#![allow(unused, clippy::excessive_precision)]
 
 use crate::*;
 
@@ -9,47 +7,97 @@ crate trait Ln {
     fn ln(self) -> Self;
 }
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.log.v2f32"]
-    fn ln_v2f32(x: f32x2) -> f32x2;
-    #[link_name = "llvm.log.v4f32"]
-    fn ln_v4f32(x: f32x4) -> f32x4;
-    #[link_name = "llvm.log.v8f32"]
-    fn ln_v8f32(x: f32x8) -> f32x8;
-    #[link_name = "llvm.log.v16f32"]
-    fn ln_v16f32(x: f32x16) -> f32x16;
-    /* FIXME 64-bit lngle elem vectors
-    #[link_name = "llvm.log.v1f64"]
-    fn ln_v1f64(x: f64x1) -> f64x1;
-     */
-    #[link_name = "llvm.log.v2f64"]
-    fn ln_v2f64(x: f64x2) -> f64x2;
-    #[link_name = "llvm.log.v4f64"]
-    fn ln_v4f64(x: f64x4) -> f64x4;
-    #[link_name = "llvm.log.v8f64"]
-    fn ln_v8f64(x: f64x8) -> f64x8;
-
-    #[link_name = "llvm.log.f32"]
-    fn ln_f32(x: f32) -> f32;
-    #[link_name = "llvm.log.f64"]
-    fn ln_f64(x: f64) -> f64;
+// This is musl's `logf`/`log`: `x` is reduced to `2^k * (1 + f)` with
+// `sqrt(2) / 2 < 1 + f < sqrt(2)`, and
+// `ln(1 + f) = f - f^2 / 2 + s * (f^2 / 2 + R(s^2))` with
+// `s = f / (2 + f)` and a polynomial `R`.
+macro_rules! define_ln {
+    ($name:ident, $id:ident, $iid:ident, $sub_scale:expr, $sub_k:expr,
+     $one:expr, $sqrt_half:expr, $mant:expr, $bias:expr, $mant_mask:expr,
+     $ln2_hi:expr, $ln2_lo:expr, |$z:ident, $w:ident| $r:expr) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let zero = $id::splat(0.);
+            let one = $id::splat(1.);
+
+            // Subnormals are scaled into the normal range:
+            let sub = x.lt($id::MIN_POSITIVE);
+            let xs = sub.select(x * $id::splat($sub_scale), x);
+            let k = sub.select($iid::splat($sub_k), $iid::splat(0));
+
+            let ix: $iid = unsafe { transmute(xs) };
+            let ix = ix + $iid::splat($one - $sqrt_half);
+            let k = k + (ix >> $mant) - $iid::splat($bias);
+            let ix = (ix & $iid::splat($mant_mask)) + $iid::splat($sqrt_half);
+            let f = unsafe { transmute::<_, $id>(ix) } - one;
+
+            let hfsq = $id::splat(0.5) * f * f;
+            let s = f / ($id::splat(2.) + f);
+            let $z = s * s;
+            let $w = $z * $z;
+            let r = $r;
+            let dk: $id = k.cast();
+            let r = s * (hfsq + r) + dk * $id::splat($ln2_lo) - hfsq + f
+                + dk * $id::splat($ln2_hi);
+
+            // ln(+-0) = -inf, ln(inf) = inf, and ln(x) is NaN for x < 0 and
+            // for NaNs:
+            let r = x.eq(zero).select($id::NEG_INFINITY, r);
+            let r = x.eq($id::INFINITY).select(x, r);
+            x.ge(zero).select(r, $id::NAN)
+        }
+    };
+    (f32 => $name:ident, $id:ident, $iid:ident) => {
+        define_ln!(
+            $name, $id, $iid, 33_554_432., -25,
+            0x3f80_0000, 0x3f35_04f3, 23, 0x7f, 0x007f_ffff,
+            6.931_381_225_6e-1, 9.058_000_614_5e-6,
+            |z, w| {
+                let t1 = w * ($id::splat(4.000_097_215_2e-1)
+                    + w * $id::splat(2.427_907_884_1e-1));
+                let t2 = z * ($id::splat(6.666_666_269_3e-1)
+                    + w * $id::splat(2.849_878_668_8e-1));
+                t2 + t1
+            }
+        );
+    };
+    (f64 => $name:ident, $id:ident, $iid:ident) => {
+        define_ln!(
+            $name, $id, $iid, 18_014_398_509_481_984., -54,
+            0x3ff0_0000_0000_0000, 0x3fe6_a09e_667f_3bcd, 52, 0x3ff,
+            0x000f_ffff_ffff_ffff,
+            6.931_471_803_691_238_164_90e-1, 1.908_214_929_270_587_700_02e-10,
+            |z, w| {
+                let t1 = w * ($id::splat(3.999_999_999_940_941_908e-1)
+                    + w * ($id::splat(2.222_219_843_214_978_396e-1)
+                    + w * $id::splat(1.531_383_769_920_937_332e-1)));
+                let t2 = z * ($id::splat(6.666_666_666_666_735_130e-1)
+                    + w * ($id::splat(2.857_142_874_366_239_149e-1)
+                    + w * ($id::splat(1.818_357_216_161_805_012e-1)
+                    + w * $id::splat(1.479_819_860_511_658_591e-1))));
+                t2 + t1
+            }
+        );
+    };
 }
 
+define_ln!(f32 => ln_v2f32, f32x2, i32x2);
+define_ln!(f32 => ln_v4f32, f32x4, i32x4);
+define_ln!(f32 => ln_v8f32, f32x8, i32x8);
+define_ln!(f32 => ln_v16f32, f32x16, i32x16);
+
+define_ln!(f64 => ln_v2f64, f64x2, i64x2);
+define_ln!(f64 => ln_v4f64, f64x4, i64x4);
+define_ln!(f64 => ln_v8f64, f64x8, i64x8);
+
 gen_unary_impl_table!(Ln, ln);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_unary!(f32x2[f32; 2]: ln_f32);
-        impl_unary!(f32x4[f32; 4]: ln_f32);
-        impl_unary!(f32x8[f32; 8]: ln_f32);
-        impl_unary!(f32x16[f32; 16]: ln_f32);
-
-        impl_unary!(f64x2[f64; 2]: ln_f64);
-        impl_unary!(f64x4[f64; 4]: ln_f64);
-        impl_unary!(f64x8[f64; 8]: ln_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -89,18 +137,18 @@ cfg_if! {
                 impl_unary!(f64x2: Sleef_logd2_u10sse2);
                 impl_unary!(f64x4[h => f64x2]: Sleef_logd2_u10sse2);
             } else {
-                impl_unary!(f32x2[f32; 2]: ln_f32);
-                impl_unary!(f32x16: ln_v16f32);
-                impl_unary!(f64x8: ln_v8f64);
-
+                impl_unary!(f32x2: ln_v2f32);
                 impl_unary!(f32x4: ln_v4f32);
                 impl_unary!(f32x8: ln_v8f32);
+                impl_unary!(f32x16: ln_v16f32);
+
                 impl_unary!(f64x2: ln_v2f64);
                 impl_unary!(f64x4: ln_v4f64);
+                impl_unary!(f64x8: ln_v8f64);
             }
         }
     } else {
-        impl_unary!(f32x2[f32; 2]: ln_f32);
+        impl_unary!(f32x2: ln_v2f32);
         impl_unary!(f32x4: ln_v4f32);
         impl_unary!(f32x8: ln_v8f32);
         impl_unary!(f32x16: ln_v16f32);
@@ -0,0 +1,93 @@
+//! Vertical floating-point `ln_1p`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Ln1p {
+    fn ln_1p(self) -> Self;
+}
+
+// `ln(1 + x)` computed with `ln`, using Kahan's correction for the
+// rounding error of `1 + x`: `u = 1 + x` is rounded, but `u - 1` is exact,
+// and `ln(u) * x / (u - 1)` is accurate for all `x`.
+macro_rules! define_ln_1p {
+    ($name:ident, $id:ident) => {
+        fn $name(x: $id) -> $id {
+            let one = $id::splat(1.);
+            let u = one + x;
+            let r = u.ln() * (x / (u - one));
+            // If `u == 1` then `ln(1 + x) == x`, and `inf / inf` would
+            // produce a NaN.
+            u.eq(one).select(x, u.eq($id::INFINITY).select(u, r))
+        }
+    };
+}
+
+define_ln_1p!(ln_1p_v2f32, f32x2);
+define_ln_1p!(ln_1p_v4f32, f32x4);
+define_ln_1p!(ln_1p_v8f32, f32x8);
+define_ln_1p!(ln_1p_v16f32, f32x16);
+
+define_ln_1p!(ln_1p_v2f64, f64x2);
+define_ln_1p!(ln_1p_v4f64, f64x4);
+define_ln_1p!(ln_1p_v8f64, f64x8);
+
+gen_unary_impl_table!(Ln1p, ln_1p);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log1pf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_log1pf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_log1pd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_log1pf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_log1pf8_u10avx2);
+                impl_unary!(f64x2: Sleef_log1pd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_log1pd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log1pf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_log1pf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_log1pd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_log1pf4_u10sse4);
+                impl_unary!(f32x8: Sleef_log1pf8_u10avx);
+                impl_unary!(f64x2: Sleef_log1pd2_u10sse4);
+                impl_unary!(f64x4: Sleef_log1pd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log1pf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_log1pf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_log1pd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_log1pf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_log1pf4_u10sse4);
+                impl_unary!(f64x2: Sleef_log1pd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_log1pd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: ln_1p_v2f32);
+                impl_unary!(f32x4: ln_1p_v4f32);
+                impl_unary!(f32x8: ln_1p_v8f32);
+                impl_unary!(f32x16: ln_1p_v16f32);
+
+                impl_unary!(f64x2: ln_1p_v2f64);
+                impl_unary!(f64x4: ln_1p_v4f64);
+                impl_unary!(f64x8: ln_1p_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: ln_1p_v2f32);
+        impl_unary!(f32x4: ln_1p_v4f32);
+        impl_unary!(f32x8: ln_1p_v8f32);
+        impl_unary!(f32x16: ln_1p_v16f32);
+
+        impl_unary!(f64x2: ln_1p_v2f64);
+        impl_unary!(f64x4: ln_1p_v4f64);
+        impl_unary!(f64x8: ln_1p_v8f64);
+    }
+}
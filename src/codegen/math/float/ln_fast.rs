@@ -0,0 +1,131 @@
+//! Vertical floating-point `ln_fast`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+crate trait LnFast {
+    fn ln_fast(self) -> Self;
+}
+
+// `x` is reduced as in `ln` to `2^k * (1 + f)` with
+// `sqrt(2) / 2 < 1 + f < sqrt(2)`, and
+// `ln(1 + f) = f - f^2 / 2 + f^3 * P(f)` with a degree 6 minimax polynomial
+// `P`, which avoids the division of `ln`.
+macro_rules! define_ln_fast {
+    ($name:ident, $id:ident, $iid:ident) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let zero = $id::splat(0.);
+            let one = $id::splat(1.);
+
+            // Subnormals are scaled into the normal range:
+            let sub = x.lt($id::MIN_POSITIVE);
+            let xs = sub.select(x * $id::splat(33_554_432.), x);
+            let k = sub.select($iid::splat(-25), $iid::splat(0));
+
+            let ix: $iid = unsafe { transmute(xs) };
+            let ix = ix + $iid::splat(0x3f80_0000 - 0x3f35_04f3);
+            let k = k + (ix >> 23) - $iid::splat(0x7f);
+            let ix = (ix & $iid::splat(0x007f_ffff)) + $iid::splat(0x3f35_04f3);
+            let f = unsafe { transmute::<_, $id>(ix) } - one;
+
+            let z = f * f;
+            let p = $id::splat(9.048_784_427_322_608e-2);
+            let p = p * f + $id::splat(-1.403_089_219_839_547_5e-1);
+            let p = p * f + $id::splat(1.470_389_916_571_866_7e-1);
+            let p = p * f + $id::splat(-1.660_271_885_298_187_6e-1);
+            let p = p * f + $id::splat(1.998_422_324_726_039_6e-1);
+            let p = p * f + $id::splat(-2.500_070_255_067_717_3e-1);
+            let p = p * f + $id::splat(3.333_341_539_881_215e-1);
+            let hfsq = $id::splat(0.5) * z;
+            let dk: $id = k.cast();
+            let r = f * z * p + dk * $id::splat(9.058_000_614_5e-6) - hfsq + f
+                + dk * $id::splat(6.931_381_225_6e-1);
+
+            // ln(+-0) = -inf, ln(inf) = inf, and ln(x) is NaN for x < 0 and
+            // for NaNs:
+            let r = x.eq(zero).select($id::NEG_INFINITY, r);
+            let r = x.eq($id::INFINITY).select(x, r);
+            x.ge(zero).select(r, $id::NAN)
+        }
+    };
+}
+
+define_ln_fast!(ln_fast_v2f32, f32x2, i32x2);
+define_ln_fast!(ln_fast_v4f32, f32x4, i32x4);
+define_ln_fast!(ln_fast_v8f32, f32x8, i32x8);
+define_ln_fast!(ln_fast_v16f32, f32x16, i32x16);
+
+// For `f64` a polynomial without the division would be much longer, so
+// this is `ln`.
+macro_rules! define_ln_fast_f64 {
+    ($name:ident, $id:ident) => {
+        fn $name(x: $id) -> $id {
+            x.ln()
+        }
+    };
+}
+
+define_ln_fast_f64!(ln_fast_v2f64, f64x2);
+define_ln_fast_f64!(ln_fast_v4f64, f64x4);
+define_ln_fast_f64!(ln_fast_v8f64, f64x8);
+
+gen_unary_impl_table!(LnFast, ln_fast);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_logf4_u35avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_logf8_u35avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_logd4_u35avx2);
+
+                impl_unary!(f32x4: Sleef_logf4_u35avx2128);
+                impl_unary!(f32x8: Sleef_logf8_u35avx2);
+                impl_unary!(f64x2: Sleef_logd2_u35avx2128);
+                impl_unary!(f64x4: Sleef_logd4_u35avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_logf4_u35sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_logf8_u35avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_logd4_u35avx);
+
+                impl_unary!(f32x4: Sleef_logf4_u35sse4);
+                impl_unary!(f32x8: Sleef_logf8_u35avx);
+                impl_unary!(f64x2: Sleef_logd2_u35sse4);
+                impl_unary!(f64x4: Sleef_logd4_u35avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_logf4_u35sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_logf4_u35sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_logd2_u35sse4);
+
+                impl_unary!(f32x4: Sleef_logf4_u35sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_logf4_u35sse4);
+                impl_unary!(f64x2: Sleef_logd2_u35sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_logd2_u35sse4);
+            } else {
+                impl_unary!(f32x2: ln_fast_v2f32);
+                impl_unary!(f32x4: ln_fast_v4f32);
+                impl_unary!(f32x8: ln_fast_v8f32);
+                impl_unary!(f32x16: ln_fast_v16f32);
+
+                impl_unary!(f64x2: ln_fast_v2f64);
+                impl_unary!(f64x4: ln_fast_v4f64);
+                impl_unary!(f64x8: ln_fast_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: ln_fast_v2f32);
+        impl_unary!(f32x4: ln_fast_v4f32);
+        impl_unary!(f32x8: ln_fast_v8f32);
+        impl_unary!(f32x16: ln_fast_v16f32);
+
+        impl_unary!(f64x2: ln_fast_v2f64);
+        impl_unary!(f64x4: ln_fast_v4f64);
+        impl_unary!(f64x8: ln_fast_v8f64);
+    }
+}
@@ -0,0 +1,130 @@
+//! Vertical floating-point `log10`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Log10 {
+    fn log10(self) -> Self;
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.log10.v2f32"]
+    fn log10_v2f32(x: f32x2) -> f32x2;
+    #[link_name = "llvm.log10.v4f32"]
+    fn log10_v4f32(x: f32x4) -> f32x4;
+    #[link_name = "llvm.log10.v8f32"]
+    fn log10_v8f32(x: f32x8) -> f32x8;
+    #[link_name = "llvm.log10.v16f32"]
+    fn log10_v16f32(x: f32x16) -> f32x16;
+    /* FIXME 64-bit single elem vectors
+    #[link_name = "llvm.log10.v1f64"]
+    fn log10_v1f64(x: f64x1) -> f64x1;
+     */
+    #[link_name = "llvm.log10.v2f64"]
+    fn log10_v2f64(x: f64x2) -> f64x2;
+    #[link_name = "llvm.log10.v4f64"]
+    fn log10_v4f64(x: f64x4) -> f64x4;
+    #[link_name = "llvm.log10.v8f64"]
+    fn log10_v8f64(x: f64x8) -> f64x8;
+
+    #[link_name = "llvm.log10.f32"]
+    fn log10_f32(x: f32) -> f32;
+    #[link_name = "llvm.log10.f64"]
+    fn log10_f64(x: f64) -> f64;
+}
+
+// The LLVM intrinsics call the `log10` of the C library of the target,
+// whose results differ across targets; the deterministic math mode calls
+// the `libm` version on each lane instead.
+fn libm_log10_f32(x: f32) -> f32 {
+    libm::log10f(x)
+}
+
+fn libm_log10_f64(x: f64) -> f64 {
+    libm::log10(x)
+}
+
+gen_unary_impl_table!(Log10, log10);
+
+cfg_if! {
+    if #[cfg(feature = "deterministic_math")] {
+        impl_unary!(f32x2[f32; 2]: libm_log10_f32);
+        impl_unary!(f32x4[f32; 4]: libm_log10_f32);
+        impl_unary!(f32x8[f32; 8]: libm_log10_f32);
+        impl_unary!(f32x16[f32; 16]: libm_log10_f32);
+
+        impl_unary!(f64x2[f64; 2]: libm_log10_f64);
+        impl_unary!(f64x4[f64; 4]: libm_log10_f64);
+        impl_unary!(f64x8[f64; 8]: libm_log10_f64);
+    } else if #[cfg(target_arch = "s390x")] {
+        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+        impl_unary!(f32x2[f32; 2]: log10_f32);
+        impl_unary!(f32x4[f32; 4]: log10_f32);
+        impl_unary!(f32x8[f32; 8]: log10_f32);
+        impl_unary!(f32x16[f32; 16]: log10_f32);
+
+        impl_unary!(f64x2[f64; 2]: log10_f64);
+        impl_unary!(f64x4[f64; 4]: log10_f64);
+        impl_unary!(f64x8[f64; 8]: log10_f64);
+    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log10f4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_log10f8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_log10d4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_log10f4_u10avx2128);
+                impl_unary!(f32x8: Sleef_log10f8_u10avx2);
+                impl_unary!(f64x2: Sleef_log10d2_u10avx2128);
+                impl_unary!(f64x4: Sleef_log10d4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log10f4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_log10f8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_log10d4_u10avx);
+
+                impl_unary!(f32x4: Sleef_log10f4_u10sse4);
+                impl_unary!(f32x8: Sleef_log10f8_u10avx);
+                impl_unary!(f64x2: Sleef_log10d2_u10sse4);
+                impl_unary!(f64x4: Sleef_log10d4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log10f4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_log10f4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_log10d2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_log10f4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_log10f4_u10sse4);
+                impl_unary!(f64x2: Sleef_log10d2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_log10d2_u10sse4);
+            } else if #[cfg(target_feature = "sse2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_log10f4_u10sse2);
+                impl_unary!(f32x16[q => f32x4]: Sleef_log10f4_u10sse2);
+                impl_unary!(f64x8[q => f64x2]: Sleef_log10d2_u10sse2);
+
+                impl_unary!(f32x4: Sleef_log10f4_u10sse2);
+                impl_unary!(f32x8[h => f32x4]: Sleef_log10f4_u10sse2);
+                impl_unary!(f64x2: Sleef_log10d2_u10sse2);
+                impl_unary!(f64x4[h => f64x2]: Sleef_log10d2_u10sse2);
+            } else {
+                impl_unary!(f32x2[f32; 2]: log10_f32);
+                impl_unary!(f32x16: log10_v16f32);
+                impl_unary!(f64x8: log10_v8f64);
+
+                impl_unary!(f32x4: log10_v4f32);
+                impl_unary!(f32x8: log10_v8f32);
+                impl_unary!(f64x2: log10_v2f64);
+                impl_unary!(f64x4: log10_v4f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2[f32; 2]: log10_f32);
+        impl_unary!(f32x4: log10_v4f32);
+        impl_unary!(f32x8: log10_v8f32);
+        impl_unary!(f32x16: log10_v16f32);
+
+        impl_unary!(f64x2: log10_v2f64);
+        impl_unary!(f64x4: log10_v4f64);
+        impl_unary!(f64x8: log10_v8f64);
+    }
+}
@@ -175,10 +175,10 @@ macro_rules! impl_tertiary_ {
                     let mut x = U { vec: self }.scalars;
                     let y = U { vec: y }.scalars;
                     let z = U { vec: z }.scalars;
-                    for (x, (y, z)) in (&mut scalars).zip(&y).zip(&z) {
-                        *i = $fun(*i, *y, *z);
+                    for (x, (y, z)) in x.iter_mut().zip(y.iter().zip(&z)) {
+                        *x = $fun(*x, *y, *z);
                     }
-                    U { vec: x }.vec
+                    U { scalars: x }.vec
                 }
             }
         }
@@ -31,10 +31,32 @@ extern "C" {
     fn fma_v8f64(x: f64x8, y: f64x8, z: f64x8) -> f64x8;
 }
 
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.fma.f32"]
+    fn fma_f32(x: f32, y: f32, z: f32) -> f32;
+    #[link_name = "llvm.fma.f64"]
+    fn fma_f64(x: f64, y: f64, z: f64) -> f64;
+}
+
 gen_tertiary_impl_table!(MulAdd, mul_add);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
+    if #[cfg(all(target_arch = "s390x", feature = "deterministic_math"))] {
+        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+        //
+        // The unfused fallback below differs from the other targets, so
+        // the deterministic math mode fuses each lane with the scalar
+        // intrinsics instead.
+        impl_tertiary!(f32x2[f32; 2]: fma_f32);
+        impl_tertiary!(f32x4[f32; 4]: fma_f32);
+        impl_tertiary!(f32x8[f32; 8]: fma_f32);
+        impl_tertiary!(f32x16[f32; 16]: fma_f32);
+
+        impl_tertiary!(f64x2[f64; 2]: fma_f64);
+        impl_tertiary!(f64x4[f64; 4]: fma_f64);
+        impl_tertiary!(f64x8[f64; 8]: fma_f64);
+    } else if #[cfg(target_arch = "s390x")] {
         // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
         macro_rules! impl_broken {
             ($id:ident) => {
@@ -55,7 +77,11 @@ cfg_if! {
         impl_broken!(f64x2);
         impl_broken!(f64x4);
         impl_broken!(f64x8);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    } else if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -7,7 +7,7 @@ crate trait MulAddE {
     fn mul_adde(self, y: Self, z: Self) -> Self;
 }
 
-#[cfg(not(target_arch = "s390x"))]
+#[cfg(not(any(target_arch = "s390x", feature = "deterministic_math")))]
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.fmuladd.v2f32"]
@@ -35,14 +35,17 @@ macro_rules! impl_mul_adde {
         impl MulAddE for $id {
             #[inline]
             fn mul_adde(self, y: Self, z: Self) -> Self {
-                #[cfg(not(target_arch = "s390x"))]
+                #[cfg(not(any(target_arch = "s390x", feature = "deterministic_math")))]
                 {
                     use crate::mem::transmute;
                     unsafe { transmute($fn(transmute(self), transmute(y), transmute(z))) }
                 }
-                #[cfg(target_arch = "s390x")]
+                // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+                //
+                // Whether `fmuladd` is fused depends on the target, so the
+                // deterministic math mode never fuses.
+                #[cfg(any(target_arch = "s390x", feature = "deterministic_math"))]
                 {
-                    // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
                     self * y + z
                 }
             }
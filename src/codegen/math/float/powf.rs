@@ -1,55 +1,201 @@
 //! Vertical floating-point `powf`
-#![allow(unused)]
-
-// FIXME 64-bit powfgle elem vectors mispowfg
+#![allow(unused, clippy::excessive_precision)]
 
 use crate::*;
 
+use super::exp::ExpLo;
+
 crate trait Powf {
     fn powf(self, x: Self) -> Self;
 }
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.pow.v2f32"]
-    fn powf_v2f32(x: f32x2, y: f32x2) -> f32x2;
-    #[link_name = "llvm.pow.v4f32"]
-    fn powf_v4f32(x: f32x4, y: f32x4) -> f32x4;
-    #[link_name = "llvm.pow.v8f32"]
-    fn powf_v8f32(x: f32x8, y: f32x8) -> f32x8;
-    #[link_name = "llvm.pow.v16f32"]
-    fn powf_v16f32(x: f32x16, y: f32x16) -> f32x16;
-    /* FIXME 64-bit powfgle elem vectors
-    #[link_name = "llvm.pow.v1f64"]
-    fn powf_v1f64(x: f64x1, y: f64x1) -> f64x1;
-     */
-    #[link_name = "llvm.pow.v2f64"]
-    fn powf_v2f64(x: f64x2, y: f64x2) -> f64x2;
-    #[link_name = "llvm.pow.v4f64"]
-    fn powf_v4f64(x: f64x4, y: f64x4) -> f64x4;
-    #[link_name = "llvm.pow.v8f64"]
-    fn powf_v8f64(x: f64x8, y: f64x8) -> f64x8;
-
-    #[link_name = "llvm.pow.f32"]
-    fn powf_f32(x: f32, y: f32) -> f32;
-    #[link_name = "llvm.pow.f64"]
-    fn powf_f64(x: f64, y: f64) -> f64;
+// Fixes up the special cases of `pow(x, y)` in `r = e^(y * ln(|x|))`.
+// `$two_mant` is `2^(mantissa bits)`: all floats larger than that are even
+// integers.
+macro_rules! pow_special_cases {
+    ($x:expr, $y:expr, $r:expr, $id:ident, $iid:ident, $two_mant:expr) => {{
+        use crate::mem::transmute;
+        let (x, y, r) = ($x, $y, $r);
+        let zero = $id::splat(0.);
+        let one = $id::splat(1.);
+        let inf = $id::INFINITY;
+        let a = x.abs();
+
+        // |x| == 0, |x| == inf, |y| == inf, and NaNs:
+        let r = a.eq(zero).select(y.lt(zero).select(inf, zero), r);
+        let r = a.eq(inf).select(y.lt(zero).select(zero, inf), r);
+        let r = y.eq(inf).select(
+            a.lt(one).select(zero, a.gt(one).select(inf, one)),
+            r,
+        );
+        let r = y.eq(-inf).select(
+            a.lt(one).select(inf, a.gt(one).select(zero, one)),
+            r,
+        );
+        let r = (x.ne(x) | y.ne(y)).select($id::NAN, r);
+
+        // If x is negative the result is negative for odd integers y, and
+        // NaN for finite x and non-integer y. Below `$two_mant` the parity
+        // of an integer y is the lowest bit of `y + $two_mant`.
+        let ay = y.abs();
+        let two_mant = $id::splat($two_mant);
+        let t = ay + two_mant;
+        let large_y = ay.ge(two_mant);
+        let is_int = large_y | (t - two_mant).eq(ay);
+        let t: $iid = unsafe { transmute(t) };
+        let is_odd = !large_y & is_int & (t & $iid::splat(1)).eq($iid::splat(1));
+        let neg: $iid = unsafe { transmute(x) };
+        let neg = neg.lt($iid::splat(0));
+        let r = (neg & is_odd).select(-r, r);
+        let r = (x.lt(zero) & !is_int & a.ne(inf)).select($id::NAN, r);
+
+        // pow(x, 0) = pow(1, y) = 1, even for NaNs:
+        (y.eq(zero) | x.eq(one)).select(one, r)
+    }};
+}
+
+// For `f32`, `e^(y * ln(|x|))` is computed with `f64` lanes, where the
+// rounding errors are negligible.
+macro_rules! define_powf_f32 {
+    ($name:ident, $id:ident, $iid:ident, $wid:ident) => {
+        fn $name(x: $id, y: $id) -> $id {
+            let a: $wid = x.abs().cast();
+            let b: $wid = y.cast();
+            let r: $id = (b * a.ln()).exp().cast();
+            pow_special_cases!(x, y, r, $id, $iid, 8_388_608.)
+        }
+    };
+}
+
+define_powf_f32!(powf_v2f32, f32x2, i32x2, f64x2);
+define_powf_f32!(powf_v4f32, f32x4, i32x4, f64x4);
+define_powf_f32!(powf_v8f32, f32x8, i32x8, f64x8);
+
+// Exact sum `a + b = s + e`.
+macro_rules! two_sum {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        let s = a + b;
+        let bb = s - a;
+        (s, (a - (s - bb)) + (b - bb))
+    }};
+}
+
+// Exact sum `a + b = s + e` for `|a| >= |b|`.
+macro_rules! fast_two_sum {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        let s = a + b;
+        (s, b - (s - a))
+    }};
 }
 
+// Exact product `a * b = p + e` (Dekker), for `|a|, |b| < 2^996`.
+macro_rules! two_prod {
+    ($a:expr, $b:expr, $id:ident) => {{
+        let (a, b) = ($a, $b);
+        let split = $id::splat(134_217_729.); // 2^27 + 1
+        let c = split * a;
+        let ah = c - (c - a);
+        let al = a - ah;
+        let c = split * b;
+        let bh = c - (c - b);
+        let bl = b - bh;
+        let p = a * b;
+        (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+    }};
+}
+
+// For `f64`, `ln(|x|)` and `y * ln(|x|)` are computed as double-doubles.
+// `ln(|x|)` uses the same argument reduction as `ln`, and
+// `ln(1 + f) = 2 * atanh(s) = 2s + 2/3 s^3 + 2/5 s^5 + ...` with
+// `s = f / (2 + f)`, where the first two terms are computed exactly.
+macro_rules! define_powf_f64 {
+    ($name:ident, $id:ident, $iid:ident) => {
+        fn $name(x: $id, y: $id) -> $id {
+            use crate::mem::transmute;
+            let zero = $id::splat(0.);
+            let one = $id::splat(1.);
+            let two = $id::splat(2.);
+            let a = x.abs();
+
+            // |x| = 2^k * (1 + f) with sqrt(2) / 2 < 1 + f < sqrt(2):
+            let sub = a.lt($id::MIN_POSITIVE);
+            let xs = sub.select(a * $id::splat(18_014_398_509_481_984.), a);
+            let k = sub.select($iid::splat(-54), $iid::splat(0));
+            let ix: $iid = unsafe { transmute(xs) };
+            let ix = ix + $iid::splat(0x3ff0_0000_0000_0000 - 0x3fe6_a09e_667f_3bcd);
+            let k = k + (ix >> 52) - $iid::splat(0x3ff);
+            let ix = (ix & $iid::splat(0x000f_ffff_ffff_ffff))
+                + $iid::splat(0x3fe6_a09e_667f_3bcd);
+            let f = unsafe { transmute::<_, $id>(ix) } - one;
+
+            // s + s_lo = f / (2 + f):
+            let d = two + f;
+            let d_lo = (two - d) + f;
+            let s = f / d;
+            let (p, pe) = two_prod!(s, d, $id);
+            let s_lo = (((f - p) - pe) - s * d_lo) / d;
+
+            // th + tl = 2/3 s^3:
+            let (z, z_lo) = two_prod!(s, s, $id);
+            let (c, c_lo) = two_prod!(z, s, $id);
+            let c_lo = c_lo + z_lo * s;
+            let two_thirds = $id::splat(2. / 3.);
+            let (th, tl) = two_prod!(c, two_thirds, $id);
+            let tl = tl + (c * $id::splat(3.700_743_415_417_188e-17)
+                + c_lo * two_thirds);
+
+            // the rest of the series:
+            let t = $id::splat(2. / 25.);
+            let t = t * z + $id::splat(2. / 23.);
+            let t = t * z + $id::splat(2. / 21.);
+            let t = t * z + $id::splat(2. / 19.);
+            let t = t * z + $id::splat(2. / 17.);
+            let t = t * z + $id::splat(2. / 15.);
+            let t = t * z + $id::splat(2. / 13.);
+            let t = t * z + $id::splat(2. / 11.);
+            let t = t * z + $id::splat(2. / 9.);
+            let t = t * z + $id::splat(2. / 7.);
+            let t = t * z + $id::splat(2. / 5.);
+            let rest = c * z * t;
+
+            // h + l = ln(1 + f), lh + ll = ln(|x|):
+            let (h, l) = fast_two_sum!(two * s, th);
+            let l = l + (tl + (two * s_lo * (one + z) + rest));
+            let dk: $id = k.cast();
+            let (lh, ll) = two_sum!(dk * $id::splat(6.931_471_803_691_238_164_90e-1), h);
+            let ll = ll + (l + dk * $id::splat(1.908_214_929_270_587_700_02e-10));
+
+            // wh + wl = y * ln(|x|):
+            let (ph, pl) = two_prod!(y, lh, $id);
+            let pl = pl + y * ll;
+            let (wh, wl) = fast_two_sum!(ph, pl);
+            let r = ExpLo::exp_lo(wh, wl);
+            // `two_prod` overflows for huge |y|, but then the result is
+            // either zero or infinity:
+            let r = ph.abs().gt($id::splat(2000.)).select(
+                ph.gt(zero).select($id::INFINITY, zero),
+                r,
+            );
+
+            pow_special_cases!(x, y, r, $id, $iid, 4_503_599_627_370_496.)
+        }
+    };
+}
+
+define_powf_f64!(powf_v2f64, f64x2, i64x2);
+define_powf_f64!(powf_v4f64, f64x4, i64x4);
+define_powf_f64!(powf_v8f64, f64x8, i64x8);
+
 gen_binary_impl_table!(Powf, powf);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_binary!(f32x2[f32; 2]: powf_f32);
-        impl_binary!(f32x4[f32; 4]: powf_f32);
-        impl_binary!(f32x8[f32; 8]: powf_f32);
-        impl_binary!(f32x16[f32; 16]: powf_f32);
-
-        impl_binary!(f64x2[f64; 2]: powf_f64);
-        impl_binary!(f64x4[f64; 4]: powf_f64);
-        impl_binary!(f64x8[f64; 8]: powf_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -89,10 +235,10 @@ cfg_if! {
                 impl_binary!(f64x2: Sleef_powd2_u10sse2);
                 impl_binary!(f64x4[h => f64x2]: Sleef_powd2_u10sse2);
             } else {
-                impl_binary!(f32x2[f32; 2]: powf_f32);
+                impl_binary!(f32x2: powf_v2f32);
                 impl_binary!(f32x4: powf_v4f32);
                 impl_binary!(f32x8: powf_v8f32);
-                impl_binary!(f32x16: powf_v16f32);
+                impl_binary!(f32x16[h => f32x8]: powf_v8f32);
 
                 impl_binary!(f64x2: powf_v2f64);
                 impl_binary!(f64x4: powf_v4f64);
@@ -100,10 +246,10 @@ cfg_if! {
             }
         }
     } else {
-        impl_binary!(f32x2[f32; 2]: powf_f32);
+        impl_binary!(f32x2: powf_v2f32);
         impl_binary!(f32x4: powf_v4f32);
         impl_binary!(f32x8: powf_v8f32);
-        impl_binary!(f32x16: powf_v16f32);
+        impl_binary!(f32x16[h => f32x8]: powf_v8f32);
 
         impl_binary!(f64x2: powf_v2f64);
         impl_binary!(f64x4: powf_v4f64);
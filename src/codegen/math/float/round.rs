@@ -0,0 +1,64 @@
+//! Vertical floating-point `round`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait Round {
+    fn round(self) -> Self;
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.round.v2f32"]
+    fn round_v2f32(x: f32x2) -> f32x2;
+    #[link_name = "llvm.round.v4f32"]
+    fn round_v4f32(x: f32x4) -> f32x4;
+    #[link_name = "llvm.round.v8f32"]
+    fn round_v8f32(x: f32x8) -> f32x8;
+    #[link_name = "llvm.round.v16f32"]
+    fn round_v16f32(x: f32x16) -> f32x16;
+    #[link_name = "llvm.round.v2f64"]
+    fn round_v2f64(x: f64x2) -> f64x2;
+    #[link_name = "llvm.round.v4f64"]
+    fn round_v4f64(x: f64x4) -> f64x4;
+    #[link_name = "llvm.round.v8f64"]
+    fn round_v8f64(x: f64x8) -> f64x8;
+}
+
+fn round_f32(x: f32) -> f32 {
+    libm::F32Ext::round(x)
+}
+
+fn round_f64(x: f64) -> f64 {
+    libm::F64Ext::round(x)
+}
+
+gen_unary_impl_table!(Round, round);
+
+cfg_if! {
+    if #[cfg(any(target_arch = "s390x",
+                 all(any(target_arch = "x86", target_arch = "x86_64"),
+                     not(target_feature = "sse4.1"))))] {
+        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+        //
+        // Without `roundps`/`roundpd` LLVM lowers the intrinsics to one
+        // libm call per lane, which is not available on all targets.
+        impl_unary!(f32x2[f32; 2]: round_f32);
+        impl_unary!(f32x4[f32; 4]: round_f32);
+        impl_unary!(f32x8[f32; 8]: round_f32);
+        impl_unary!(f32x16[f32; 16]: round_f32);
+
+        impl_unary!(f64x2[f64; 2]: round_f64);
+        impl_unary!(f64x4[f64; 4]: round_f64);
+        impl_unary!(f64x8[f64; 8]: round_f64);
+    } else {
+        impl_unary!(f32x2: round_v2f32);
+        impl_unary!(f32x4: round_v4f32);
+        impl_unary!(f32x8: round_v8f32);
+        impl_unary!(f32x16: round_v16f32);
+
+        impl_unary!(f64x2: round_v2f64);
+        impl_unary!(f64x4: round_v4f64);
+        impl_unary!(f64x8: round_v8f64);
+    }
+}
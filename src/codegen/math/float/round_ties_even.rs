@@ -0,0 +1,77 @@
+//! Vertical floating-point `round_ties_even`
+#![allow(unused)]
+
+use crate::*;
+
+crate trait RoundTiesEven {
+    fn round_ties_even(self) -> Self;
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.roundeven.v2f32"]
+    fn round_ties_even_v2f32(x: f32x2) -> f32x2;
+    #[link_name = "llvm.roundeven.v4f32"]
+    fn round_ties_even_v4f32(x: f32x4) -> f32x4;
+    #[link_name = "llvm.roundeven.v8f32"]
+    fn round_ties_even_v8f32(x: f32x8) -> f32x8;
+    #[link_name = "llvm.roundeven.v16f32"]
+    fn round_ties_even_v16f32(x: f32x16) -> f32x16;
+    #[link_name = "llvm.roundeven.v2f64"]
+    fn round_ties_even_v2f64(x: f64x2) -> f64x2;
+    #[link_name = "llvm.roundeven.v4f64"]
+    fn round_ties_even_v4f64(x: f64x4) -> f64x4;
+    #[link_name = "llvm.roundeven.v8f64"]
+    fn round_ties_even_v8f64(x: f64x8) -> f64x8;
+}
+
+// Adding and subtracting `2^MANTISSA_DIGITS-1` rounds to an integer using
+// the default rounding mode, i.e., to nearest with ties to even. Larger
+// values, infinities and NaNs are already integral or are returned as is.
+macro_rules! define_round_ties_even {
+    ($name:ident, $ty:ident, $uty:ident) => {
+        fn $name(x: $ty) -> $ty {
+            const TOINT: $ty = 1. / crate::$ty::EPSILON;
+            const SIGN: $uty = 1 << (crate::mem::size_of::<$uty>() * 8 - 1);
+            let a = $ty::from_bits(x.to_bits() & !SIGN);
+            if !(a < TOINT) {
+                return x;
+            }
+            let r = (a + TOINT) - TOINT;
+            $ty::from_bits(r.to_bits() | (x.to_bits() & SIGN))
+        }
+    };
+}
+
+define_round_ties_even!(round_ties_even_f32, f32, u32);
+define_round_ties_even!(round_ties_even_f64, f64, u64);
+
+gen_unary_impl_table!(RoundTiesEven, round_ties_even);
+
+cfg_if! {
+    if #[cfg(any(target_arch = "s390x",
+                 all(any(target_arch = "x86", target_arch = "x86_64"),
+                     not(target_feature = "sse4.1"))))] {
+        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
+        //
+        // Without `roundps`/`roundpd` LLVM lowers the intrinsics to one
+        // libm call per lane, which is not available on all targets.
+        impl_unary!(f32x2[f32; 2]: round_ties_even_f32);
+        impl_unary!(f32x4[f32; 4]: round_ties_even_f32);
+        impl_unary!(f32x8[f32; 8]: round_ties_even_f32);
+        impl_unary!(f32x16[f32; 16]: round_ties_even_f32);
+
+        impl_unary!(f64x2[f64; 2]: round_ties_even_f64);
+        impl_unary!(f64x4[f64; 4]: round_ties_even_f64);
+        impl_unary!(f64x8[f64; 8]: round_ties_even_f64);
+    } else {
+        impl_unary!(f32x2: round_ties_even_v2f32);
+        impl_unary!(f32x4: round_ties_even_v4f32);
+        impl_unary!(f32x8: round_ties_even_v8f32);
+        impl_unary!(f32x16: round_ties_even_v16f32);
+
+        impl_unary!(f64x2: round_ties_even_v2f64);
+        impl_unary!(f64x4: round_ties_even_v4f64);
+        impl_unary!(f64x8: round_ties_even_v8f64);
+    }
+}
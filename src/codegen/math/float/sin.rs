@@ -1,7 +1,5 @@
 //! Vertical floating-point `sin`
-#![allow(unused)]
-
-// FIXME 64-bit 1 elem vectors sin
+#![allow(unused, clippy::excessive_precision)]
 
 use crate::*;
 
@@ -9,47 +7,283 @@ crate trait Sin {
     fn sin(self) -> Self;
 }
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.sin.v2f32"]
-    fn sin_v2f32(x: f32x2) -> f32x2;
-    #[link_name = "llvm.sin.v4f32"]
-    fn sin_v4f32(x: f32x4) -> f32x4;
-    #[link_name = "llvm.sin.v8f32"]
-    fn sin_v8f32(x: f32x8) -> f32x8;
-    #[link_name = "llvm.sin.v16f32"]
-    fn sin_v16f32(x: f32x16) -> f32x16;
-    /* FIXME 64-bit single elem vectors
-    #[link_name = "llvm.sin.v1f64"]
-    fn sin_v1f64(x: f64x1) -> f64x1;
-     */
-    #[link_name = "llvm.sin.v2f64"]
-    fn sin_v2f64(x: f64x2) -> f64x2;
-    #[link_name = "llvm.sin.v4f64"]
-    fn sin_v4f64(x: f64x4) -> f64x4;
-    #[link_name = "llvm.sin.v8f64"]
-    fn sin_v8f64(x: f64x8) -> f64x8;
-
-    #[link_name = "llvm.sin.f32"]
-    fn sin_f32(x: f32) -> f32;
-    #[link_name = "llvm.sin.f64"]
-    fn sin_f64(x: f64) -> f64;
+/// Sine and cosine of `self`, shared by the portable `sin` and `cos`.
+///
+/// The argument is reduced to `y = self - n * pi / 2` with `|y| <= pi / 4`
+/// as in musl's `__rem_pio2f`/`__rem_pio2`, which is only accurate for
+/// `|self| <= SIN_COS_K_MAX`, and the sine and cosine of `y` are computed
+/// with the kernels of musl's `__sindf`/`__cosdf` and `__sin`/`__cos`. The
+/// `f32` kernels are evaluated with `f64` lanes.
+crate trait SinCosK: Sized {
+    const SIN_COS_K_MAX: Self;
+    fn sin_cos_k(self) -> (Self, Self);
+}
+
+// Selects `(sin(x), cos(x))` from the sine `s` and cosine `c` of the reduced
+// argument depending on the quadrant `n`.
+macro_rules! sin_cos_quadrant {
+    ($s:expr, $c:expr, $n:expr, $iid:ident) => {{
+        let (s, c, n) = ($s, $c, $n);
+        let one = $iid::splat(1);
+        let two = $iid::splat(2);
+        let swap = (n & one).eq(one);
+        let (s, c) = (swap.select(c, s), swap.select(s, c));
+        let s = (n & two).eq(two).select(-s, s);
+        let c = ((n + one) & two).eq(two).select(-c, c);
+        (s, c)
+    }};
+}
+
+macro_rules! impl_sin_cos_k {
+    (f32: $($id:ident, $wid:ident, $wiid:ident);*) => {
+        $(
+            impl SinCosK for $id {
+                // 2^28 * pi / 2
+                const SIN_COS_K_MAX: Self = $id::splat(4.216_574_3e8);
+                #[inline]
+                fn sin_cos_k(self) -> (Self, Self) {
+                    use crate::mem::transmute;
+                    let x: $wid = self.cast();
+
+                    // n = round(x * 2 / pi) is stored in the low bits of `t`:
+                    let toint = $wid::splat(6_755_399_441_055_744.);
+                    let t = x * $wid::splat(6.366_197_723_675_813_824_33e-1) + toint;
+                    let fnn = t - toint;
+                    let n: $wiid = unsafe { transmute(t) };
+                    let y = x - fnn * $wid::splat(1.570_796_310_901_641_845_70e0)
+                        - fnn * $wid::splat(1.589_325_477_352_819_669_16e-8);
+
+                    let z = y * y;
+                    let w = z * z;
+
+                    let r = $wid::splat(-1.983_933_483_609_663_173_47e-4)
+                        + z * $wid::splat(2.718_311_493_989_821_906_4e-6);
+                    let s = z * y;
+                    let s = (y + s * ($wid::splat(-1.666_666_664_162_652_355_95e-1)
+                        + z * $wid::splat(8.333_329_385_889_463_175_6e-3)))
+                        + s * w * r;
+                    // `-0 + 0` is `+0`: keep the sign of `sin(-0) == -0`.
+                    let s = y.eq($wid::splat(0.)).select(y, s);
+
+                    let r = $wid::splat(-1.388_676_377_460_992_946_92e-3)
+                        + z * $wid::splat(2.439_044_879_627_740_906_54e-5);
+                    let c = (($wid::splat(1.)
+                        + z * $wid::splat(-4.999_999_972_510_310_031_20e-1))
+                        + w * $wid::splat(4.166_662_332_373_906_318_94e-2))
+                        + (w * z) * r;
+
+                    let (s, c) = sin_cos_quadrant!(s, c, n, $wiid);
+                    (s.cast(), c.cast())
+                }
+            }
+        )*
+    };
+    (f64: $($id:ident, $iid:ident);*) => {
+        $(
+            impl SinCosK for $id {
+                // 2^20 * pi / 2
+                const SIN_COS_K_MAX: Self = $id::splat(1.647_099_3e6);
+                #[inline]
+                fn sin_cos_k(self) -> (Self, Self) {
+                    use crate::mem::transmute;
+                    let x = self;
+
+                    // n = round(x * 2 / pi) is stored in the low bits of `t`:
+                    let toint = $id::splat(6_755_399_441_055_744.);
+                    let t = x * $id::splat(6.366_197_723_675_813_824_33e-1) + toint;
+                    let fnn = t - toint;
+                    let n: $iid = unsafe { transmute(t) };
+
+                    // y0 + y1 = x - n * pi / 2, where pi / 2 is split into
+                    // 33-bit parts so that the products with `n` are exact:
+                    let r = x - fnn * $id::splat(1.570_796_326_734_125_614_17e0);
+                    let tt = r;
+                    let w = fnn * $id::splat(6.077_100_506_303_965_976_60e-11);
+                    let r = tt - w;
+                    let w = fnn * $id::splat(2.022_266_248_795_950_631_54e-21)
+                        - ((tt - r) - w);
+                    let tt = r;
+                    let w = fnn * $id::splat(2.022_266_248_711_166_455_80e-21);
+                    let r = tt - w;
+                    let w = fnn * $id::splat(8.478_427_660_368_899_569_97e-32)
+                        - ((tt - r) - w);
+                    let y0 = r - w;
+                    let y1 = (r - y0) - w;
+
+                    let half = $id::splat(0.5);
+                    let z = y0 * y0;
+                    let w = z * z;
+
+                    let r = $id::splat(8.333_333_333_322_489_461_24e-3)
+                        + z * ($id::splat(-1.984_126_982_985_794_931_34e-4)
+                        + z * $id::splat(2.755_731_370_707_006_767_89e-6))
+                        + z * w * ($id::splat(-2.505_076_025_340_686_341_95e-8)
+                        + z * $id::splat(1.589_690_995_211_550_102_21e-10));
+                    let v = z * y0;
+                    let s = y0 - ((z * (half * y1 - v * r) - y1)
+                        - v * $id::splat(-1.666_666_666_666_663_243_48e-1));
+
+                    let r = z * ($id::splat(4.166_666_666_666_660_190_37e-2)
+                        + z * ($id::splat(-1.388_888_888_887_410_957_49e-3)
+                        + z * $id::splat(2.480_158_728_947_672_941_78e-5)))
+                        + w * w * ($id::splat(-2.755_731_435_139_066_330_35e-7)
+                        + z * ($id::splat(2.087_572_321_298_174_827_90e-9)
+                        + z * $id::splat(-1.135_964_755_778_819_482_65e-11)));
+                    let hz = half * z;
+                    let w = $id::splat(1.) - hz;
+                    let c = w + ((($id::splat(1.) - w) - hz) + (z * r - y0 * y1));
+
+                    sin_cos_quadrant!(s, c, n, $iid)
+                }
+            }
+        )*
+    };
+}
+
+impl_sin_cos_k!(f32: f32x2, f64x2, i64x2; f32x4, f64x4, i64x4; f32x8, f64x8, i64x8);
+impl_sin_cos_k!(f64: f64x2, i64x2; f64x4, i64x4; f64x8, i64x8);
+
+/// Sine and cosine of `self`, shared by the portable `sin_fast` and
+/// `cos_fast`.
+///
+/// Like `SinCosK`, but the argument is reduced in a single step with
+/// `pi / 2` split into four parts, which is only accurate for
+/// `|self| <= SIN_COS_FAST_K_MAX`. The `f32` kernels are those of Cephes'
+/// `sinf`/`cosf` evaluated with `f32` lanes, and the `f64` kernels are
+/// those of musl's `__sin`/`__cos` without the correction for the tail of
+/// the reduced argument.
+crate trait SinCosFastK: Sized {
+    const SIN_COS_FAST_K_MAX: Self;
+    fn sin_cos_fast_k(self) -> (Self, Self);
 }
 
+macro_rules! impl_sin_cos_fast_k {
+    ($id:ident, $iid:ident, $max:expr, $toint:expr,
+     $pio2_1:expr, $pio2_2:expr, $pio2_3:expr, $pio2_4:expr,
+     |$y:ident, $z:ident, $w:ident| $sin:expr, $cos:expr) => {
+        impl SinCosFastK for $id {
+            const SIN_COS_FAST_K_MAX: Self = $id::splat($max);
+            #[inline]
+            fn sin_cos_fast_k(self) -> (Self, Self) {
+                use crate::mem::transmute;
+                let x = self;
+
+                // n = round(x * 2 / pi) is stored in the low bits of `t`:
+                let toint = $id::splat($toint);
+                let t = x * $id::splat(crate::f64::consts::FRAC_2_PI as _) + toint;
+                let fnn = t - toint;
+                let n: $iid = unsafe { transmute(t) };
+
+                let $y = x - fnn * $id::splat($pio2_1);
+                let $y = $y - fnn * $id::splat($pio2_2);
+                let $y = $y - fnn * $id::splat($pio2_3);
+                let $y = $y - fnn * $id::splat($pio2_4);
+                let $z = $y * $y;
+                let $w = $z * $z;
+
+                // `-0 + 0` is `+0`: keep the sign of `sin(-0) == -0`.
+                let s = $y.eq($id::splat(0.)).select($y, $sin);
+                sin_cos_quadrant!(s, $cos, n, $iid)
+            }
+        }
+    };
+    (f32: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_sin_cos_fast_k!(
+                $id, $iid, 8192., 12_582_912.,
+                1.570_312_5, 4.837_512_969_970_703_125e-4,
+                7.549_533_620_476_723e-8, 2.563_344_068_257_089_6e-12,
+                |y, z, w| {
+                    y + y * z * ($id::splat(-1.666_665_461_1e-1)
+                        + z * ($id::splat(8.332_160_873_6e-3)
+                        + z * $id::splat(-1.951_529_589_1e-4)))
+                },
+                {
+                    $id::splat(1.) - $id::splat(0.5) * z
+                        + w * ($id::splat(4.166_664_568_298_827e-2)
+                        + z * ($id::splat(-1.388_731_625_493_765e-3)
+                        + z * $id::splat(2.443_315_711_809_948e-5)))
+                }
+            );
+        )*
+    };
+    (f64: $($id:ident, $iid:ident);*) => {
+        $(
+            impl_sin_cos_fast_k!(
+                $id, $iid, 1.647_099_3e6, 6_755_399_441_055_744.,
+                1.570_796_326_734_125_614_17e0, 6.077_100_506_303_965_976_60e-11,
+                2.022_266_248_711_166_455_80e-21, 8.478_427_660_368_899_569_97e-32,
+                |y, z, w| {
+                    y + y * z * ($id::splat(-1.666_666_666_666_663_243_48e-1)
+                        + z * ($id::splat(8.333_333_333_322_489_461_24e-3)
+                        + z * $id::splat(-1.984_126_982_985_794_931_34e-4))
+                        + z * w * ($id::splat(2.755_731_370_707_006_767_89e-6)
+                        + z * $id::splat(-2.505_076_025_340_686_341_95e-8)
+                        + w * $id::splat(1.589_690_995_211_550_102_21e-10)))
+                },
+                {
+                    let r = z * ($id::splat(4.166_666_666_666_660_190_37e-2)
+                        + z * ($id::splat(-1.388_888_888_887_410_957_49e-3)
+                        + z * $id::splat(2.480_158_728_947_672_941_78e-5)))
+                        + w * w * ($id::splat(-2.755_731_435_139_066_330_35e-7)
+                        + z * ($id::splat(2.087_572_321_298_174_827_90e-9)
+                        + z * $id::splat(-1.135_964_755_778_819_482_65e-11)));
+                    let hz = $id::splat(0.5) * z;
+                    let one = $id::splat(1.);
+                    let w = one - hz;
+                    w + (((one - w) - hz) + z * r)
+                }
+            );
+        )*
+    };
+}
+
+impl_sin_cos_fast_k!(f32: f32x2, i32x2; f32x4, i32x4; f32x8, i32x8; f32x16, i32x16);
+impl_sin_cos_fast_k!(f64: f64x2, i64x2; f64x4, i64x4; f64x8, i64x8);
+
+
+// Lanes out of the range of `SinCosK` are rare, and computed with `libm`.
+macro_rules! define_sin {
+    ($name:ident, $id:ident, $libm:path) => {
+        fn $name(x: $id) -> $id {
+            let (s, _) = SinCosK::sin_cos_k(x);
+            let huge = x.abs().gt($id::SIN_COS_K_MAX);
+            if !huge.any() {
+                return s;
+            }
+            let mut s = s;
+            for i in 0..$id::lanes() {
+                if huge.extract(i) {
+                    s = s.replace(i, $libm(x.extract(i)));
+                }
+            }
+            s
+        }
+    };
+    (f32 => $name:ident, $id:ident) => {
+        define_sin!($name, $id, libm::sinf);
+    };
+    (f64 => $name:ident, $id:ident) => {
+        define_sin!($name, $id, libm::sin);
+    };
+}
+
+define_sin!(f32 => sin_v2f32, f32x2);
+define_sin!(f32 => sin_v4f32, f32x4);
+define_sin!(f32 => sin_v8f32, f32x8);
+
+define_sin!(f64 => sin_v2f64, f64x2);
+define_sin!(f64 => sin_v4f64, f64x4);
+define_sin!(f64 => sin_v8f64, f64x8);
+
 gen_unary_impl_table!(Sin, sin);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_unary!(f32x2[f32; 2]: sin_f32);
-        impl_unary!(f32x4[f32; 4]: sin_f32);
-        impl_unary!(f32x8[f32; 8]: sin_f32);
-        impl_unary!(f32x16[f32; 16]: sin_f32);
-
-        impl_unary!(f64x2[f64; 2]: sin_f64);
-        impl_unary!(f64x4[f64; 4]: sin_f64);
-        impl_unary!(f64x8[f64; 8]: sin_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -80,21 +314,21 @@ cfg_if! {
                 impl_unary!(f64x2: Sleef_sind2_u10sse4);
                 impl_unary!(f64x4[h => f64x2]: Sleef_sind2_u10sse4);
             } else {
-                impl_unary!(f32x2[f32; 2]: sin_f32);
-                impl_unary!(f32x16: sin_v16f32);
-                impl_unary!(f64x8: sin_v8f64);
-
+                impl_unary!(f32x2: sin_v2f32);
                 impl_unary!(f32x4: sin_v4f32);
                 impl_unary!(f32x8: sin_v8f32);
+                impl_unary!(f32x16[h => f32x8]: sin_v8f32);
+
                 impl_unary!(f64x2: sin_v2f64);
                 impl_unary!(f64x4: sin_v4f64);
+                impl_unary!(f64x8: sin_v8f64);
             }
         }
     } else {
-        impl_unary!(f32x2[f32; 2]: sin_f32);
+        impl_unary!(f32x2: sin_v2f32);
         impl_unary!(f32x4: sin_v4f32);
         impl_unary!(f32x8: sin_v8f32);
-        impl_unary!(f32x16: sin_v16f32);
+        impl_unary!(f32x16[h => f32x8]: sin_v8f32);
 
         impl_unary!(f64x2: sin_v2f64);
         impl_unary!(f64x4: sin_v4f64);
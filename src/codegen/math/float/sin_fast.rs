@@ -0,0 +1,94 @@
+//! Vertical floating-point `sin_fast`
+#![allow(unused)]
+
+use crate::*;
+
+use super::sin::SinCosFastK;
+
+crate trait SinFast {
+    fn sin_fast(self) -> Self;
+}
+
+// Lanes out of the range of `SinCosFastK` are rare, and computed with the
+// accurate `sin`.
+macro_rules! define_sin_fast {
+    ($name:ident, $id:ident) => {
+        fn $name(x: $id) -> $id {
+            let (s, _) = SinCosFastK::sin_cos_fast_k(x);
+            let huge = x.abs().gt($id::SIN_COS_FAST_K_MAX);
+            if !huge.any() {
+                return s;
+            }
+            huge.select(x.sin(), s)
+        }
+    };
+}
+
+define_sin_fast!(sin_fast_v2f32, f32x2);
+define_sin_fast!(sin_fast_v4f32, f32x4);
+define_sin_fast!(sin_fast_v8f32, f32x8);
+define_sin_fast!(sin_fast_v16f32, f32x16);
+
+define_sin_fast!(sin_fast_v2f64, f64x2);
+define_sin_fast!(sin_fast_v4f64, f64x4);
+define_sin_fast!(sin_fast_v8f64, f64x8);
+
+gen_unary_impl_table!(SinFast, sin_fast);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinf4_u35avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_sinf8_u35avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_sind4_u35avx2);
+
+                impl_unary!(f32x4: Sleef_sinf4_u35avx2128);
+                impl_unary!(f32x8: Sleef_sinf8_u35avx2);
+                impl_unary!(f64x2: Sleef_sind2_u35avx2128);
+                impl_unary!(f64x4: Sleef_sind4_u35avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinf4_u35sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_sinf8_u35avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_sind4_u35avx);
+
+                impl_unary!(f32x4: Sleef_sinf4_u35sse4);
+                impl_unary!(f32x8: Sleef_sinf8_u35avx);
+                impl_unary!(f64x2: Sleef_sind2_u35sse4);
+                impl_unary!(f64x4: Sleef_sind4_u35avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinf4_u35sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_sinf4_u35sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_sind2_u35sse4);
+
+                impl_unary!(f32x4: Sleef_sinf4_u35sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_sinf4_u35sse4);
+                impl_unary!(f64x2: Sleef_sind2_u35sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_sind2_u35sse4);
+            } else {
+                impl_unary!(f32x2: sin_fast_v2f32);
+                impl_unary!(f32x4: sin_fast_v4f32);
+                impl_unary!(f32x8: sin_fast_v8f32);
+                impl_unary!(f32x16: sin_fast_v16f32);
+
+                impl_unary!(f64x2: sin_fast_v2f64);
+                impl_unary!(f64x4: sin_fast_v4f64);
+                impl_unary!(f64x8: sin_fast_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: sin_fast_v2f32);
+        impl_unary!(f32x4: sin_fast_v4f32);
+        impl_unary!(f32x8: sin_fast_v8f32);
+        impl_unary!(f32x16: sin_fast_v16f32);
+
+        impl_unary!(f64x2: sin_fast_v2f64);
+        impl_unary!(f64x4: sin_fast_v4f64);
+        impl_unary!(f64x8: sin_fast_v8f64);
+    }
+}
@@ -0,0 +1,138 @@
+//! Vertical floating-point `sinh`
+#![allow(unused, clippy::excessive_precision)]
+
+use crate::*;
+
+crate trait Sinh {
+    fn sinh(self) -> Self;
+}
+
+macro_rules! define_sinh {
+    ($name:ident, $id:ident, $uid:ident, $ln_max:expr, [$($c:expr),* $(,)?]) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let half = $id::splat(0.5);
+            let one = $id::splat(1.);
+            let a = x.abs();
+
+            // |x| < 1: Taylor series of sinh(|x|) in `z = x * x`, which
+            // avoids the cancellation of the exponential form below.
+            let z = a * a;
+            let mut p = $id::splat(0.);
+            $(p = (p + $id::splat($c)) * z;)*
+            let small = a + a * p;
+
+            // |x| >= 1: sinh(|x|) = (e^|x| - e^-|x|) / 2
+            //
+            // Close to overflow e^|x| is not representable but sinh(|x|)
+            // might be, so it is computed as (e^(|x| / 2) / 2) * e^(|x| / 2).
+            let huge = a.gt($id::splat($ln_max));
+            let e = huge.select(half * a, a).exp();
+            let large = huge.select((half * e) * e, half * e - half / e);
+
+            let r = a.lt(one).select(small, large);
+            // sinh is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
+        }
+    };
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_sinh!(
+            $name, $id, $uid, 88.722_83,
+            [
+                1.605_904_383_682_161_33e-10,
+                2.505_210_838_544_172_02e-08,
+                2.755_731_922_398_589_25e-06,
+                1.984_126_984_126_984_13e-04,
+                8.333_333_333_333_333_22e-03,
+                1.666_666_666_666_666_57e-01,
+            ]
+        );
+    };
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_sinh!(
+            $name, $id, $uid, 709.78,
+            [
+                8.220_635_246_624_329_50e-18,
+                2.811_457_254_345_520_60e-15,
+                7.647_163_731_819_816_41e-13,
+                1.605_904_383_682_161_33e-10,
+                2.505_210_838_544_172_02e-08,
+                2.755_731_922_398_589_25e-06,
+                1.984_126_984_126_984_13e-04,
+                8.333_333_333_333_333_22e-03,
+                1.666_666_666_666_666_57e-01,
+            ]
+        );
+    };
+}
+
+define_sinh!(f32 => sinh_v2f32, f32x2, u32x2);
+define_sinh!(f32 => sinh_v4f32, f32x4, u32x4);
+define_sinh!(f32 => sinh_v8f32, f32x8, u32x8);
+define_sinh!(f32 => sinh_v16f32, f32x16, u32x16);
+
+define_sinh!(f64 => sinh_v2f64, f64x2, u64x2);
+define_sinh!(f64 => sinh_v4f64, f64x4, u64x4);
+define_sinh!(f64 => sinh_v8f64, f64x8, u64x8);
+
+gen_unary_impl_table!(Sinh, sinh);
+
+cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
+        use sleef_sys::*;
+        cfg_if! {
+            if #[cfg(target_feature = "avx2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinhf4_u10avx2128);
+                impl_unary!(f32x16[h => f32x8]: Sleef_sinhf8_u10avx2);
+                impl_unary!(f64x8[h => f64x4]: Sleef_sinhd4_u10avx2);
+
+                impl_unary!(f32x4: Sleef_sinhf4_u10avx2128);
+                impl_unary!(f32x8: Sleef_sinhf8_u10avx2);
+                impl_unary!(f64x2: Sleef_sinhd2_u10avx2128);
+                impl_unary!(f64x4: Sleef_sinhd4_u10avx2);
+            } else if #[cfg(target_feature = "avx")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinhf4_u10sse4);
+                impl_unary!(f32x16[h => f32x8]: Sleef_sinhf8_u10avx);
+                impl_unary!(f64x8[h => f64x4]: Sleef_sinhd4_u10avx);
+
+                impl_unary!(f32x4: Sleef_sinhf4_u10sse4);
+                impl_unary!(f32x8: Sleef_sinhf8_u10avx);
+                impl_unary!(f64x2: Sleef_sinhd2_u10sse4);
+                impl_unary!(f64x4: Sleef_sinhd4_u10avx);
+            } else if #[cfg(target_feature = "sse4.2")] {
+                impl_unary!(f32x2[t => f32x4]: Sleef_sinhf4_u10sse4);
+                impl_unary!(f32x16[q => f32x4]: Sleef_sinhf4_u10sse4);
+                impl_unary!(f64x8[q => f64x2]: Sleef_sinhd2_u10sse4);
+
+                impl_unary!(f32x4: Sleef_sinhf4_u10sse4);
+                impl_unary!(f32x8[h => f32x4]: Sleef_sinhf4_u10sse4);
+                impl_unary!(f64x2: Sleef_sinhd2_u10sse4);
+                impl_unary!(f64x4[h => f64x2]: Sleef_sinhd2_u10sse4);
+            } else {
+                impl_unary!(f32x2: sinh_v2f32);
+                impl_unary!(f32x4: sinh_v4f32);
+                impl_unary!(f32x8: sinh_v8f32);
+                impl_unary!(f32x16: sinh_v16f32);
+
+                impl_unary!(f64x2: sinh_v2f64);
+                impl_unary!(f64x4: sinh_v4f64);
+                impl_unary!(f64x8: sinh_v8f64);
+            }
+        }
+    } else {
+        impl_unary!(f32x2: sinh_v2f32);
+        impl_unary!(f32x4: sinh_v4f32);
+        impl_unary!(f32x8: sinh_v8f32);
+        impl_unary!(f32x16: sinh_v16f32);
+
+        impl_unary!(f64x2: sinh_v2f64);
+        impl_unary!(f64x4: sinh_v4f64);
+        impl_unary!(f64x8: sinh_v8f64);
+    }
+}
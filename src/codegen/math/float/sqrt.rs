@@ -49,7 +49,11 @@ cfg_if! {
         impl_unary!(f64x2[f64; 2]: sqrt_f64);
         impl_unary!(f64x4[f64; 4]: sqrt_f64);
         impl_unary!(f64x8[f64; 8]: sqrt_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    } else if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
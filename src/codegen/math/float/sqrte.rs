@@ -13,7 +13,11 @@ crate trait Sqrte {
 gen_unary_impl_table!(Sqrte, sqrte);
 
 cfg_if! {
-    if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -1,7 +1,5 @@
 //! Vertical floating-point `tanh`
-#![allow(unused)]
-
-// FIXME 64-bit 1 elem vectors tanh
+#![allow(unused, clippy::excessive_precision)]
 
 use crate::*;
 
@@ -9,61 +7,68 @@ crate trait Tanh {
     fn tanh(self) -> Self;
 }
 
+// This is Cephes' `tanhf`/`tanh`: a polynomial or rational approximation
+// for |x| < 0.625, and tanh(|x|) = 1 - 2 / (e^(2|x|) + 1) otherwise.
 macro_rules! define_tanh {
-    ($name:ident, $basetype:ty, $simdtype:ty, $lanes:expr, $trait:path) => {
-        fn $name(x: $simdtype) -> $simdtype {
-            use core::intrinsics::transmute;
-            let mut buf: [$basetype; $lanes] = unsafe { transmute(x) };
-            for elem in &mut buf {
-                *elem = <$basetype as $trait>::tanh(*elem);
-            }
-            unsafe { transmute(buf) }
+    ($name:ident, $id:ident, $uid:ident, |$z:ident, $a:ident| $small:expr) => {
+        fn $name(x: $id) -> $id {
+            use crate::mem::transmute;
+            let one = $id::splat(1.);
+            let $a = x.abs();
+            let $z = $a * $a;
+            let small = $small;
+
+            // e^(2|x|) overflows to infinity for large |x|, producing 1.
+            let e = ($a + $a).exp();
+            let large = one - $id::splat(2.) / (e + one);
+
+            let r = $a.lt($id::splat(0.625)).select(small, large);
+            // tanh is odd:
+            let sign: $uid = unsafe { transmute(x) };
+            let r: $uid = unsafe { transmute(r) };
+            unsafe { transmute(r | (sign & $uid::splat(!(!0 >> 1)))) }
         }
     };
-
-    (f32 => $name:ident, $type:ty, $lanes:expr) => {
-        define_tanh!($name, f32, $type, $lanes, libm::F32Ext);
+    (f32 => $name:ident, $id:ident, $uid:ident) => {
+        define_tanh!($name, $id, $uid, |z, a| {
+            let p = $id::splat(-5.704_988_727_45e-3);
+            let p = p * z + $id::splat(2.063_908_879_54e-2);
+            let p = p * z + $id::splat(-5.373_971_555_31e-2);
+            let p = p * z + $id::splat(1.333_144_220_36e-1);
+            let p = p * z + $id::splat(-3.333_328_194_22e-1);
+            p * z * a + a
+        });
     };
-
-    (f64 => $name:ident, $type:ty, $lanes:expr) => {
-        define_tanh!($name, f64, $type, $lanes, libm::F64Ext);
+    (f64 => $name:ident, $id:ident, $uid:ident) => {
+        define_tanh!($name, $id, $uid, |z, a| {
+            let p = $id::splat(-9.643_991_794_250_522_386_28e-1);
+            let p = p * z + $id::splat(-9.928_772_310_019_185_865_64e1);
+            let p = p * z + $id::splat(-1.614_687_684_417_084_479_52e3);
+            let q = z + $id::splat(1.128_116_784_916_329_314_02e2);
+            let q = q * z + $id::splat(2.235_488_390_601_004_485_83e3);
+            let q = q * z + $id::splat(4.844_063_053_251_254_860_48e3);
+            a + a * z * p / q
+        });
     };
 }
 
-// llvm does not seem to expose the hyperbolic versions of trigonometric
-// functions; we thus call the classical rust versions on all of them (which
-// stem from cmath).
-define_tanh!(f32 => tanh_v2f32, f32x2, 2);
-define_tanh!(f32 => tanh_v4f32, f32x4, 4);
-define_tanh!(f32 => tanh_v8f32, f32x8, 8);
-define_tanh!(f32 => tanh_v16f32, f32x16, 16);
+define_tanh!(f32 => tanh_v2f32, f32x2, u32x2);
+define_tanh!(f32 => tanh_v4f32, f32x4, u32x4);
+define_tanh!(f32 => tanh_v8f32, f32x8, u32x8);
+define_tanh!(f32 => tanh_v16f32, f32x16, u32x16);
 
-define_tanh!(f64 => tanh_v2f64, f64x2, 2);
-define_tanh!(f64 => tanh_v4f64, f64x4, 4);
-define_tanh!(f64 => tanh_v8f64, f64x8, 8);
-
-fn tanh_f32(x: f32) -> f32 {
-    libm::F32Ext::tanh(x)
-}
-
-fn tanh_f64(x: f64) -> f64 {
-    libm::F64Ext::tanh(x)
-}
+define_tanh!(f64 => tanh_v2f64, f64x2, u64x2);
+define_tanh!(f64 => tanh_v4f64, f64x4, u64x4);
+define_tanh!(f64 => tanh_v8f64, f64x8, u64x8);
 
 gen_unary_impl_table!(Tanh, tanh);
 
 cfg_if! {
-    if #[cfg(target_arch = "s390x")] {
-        // FIXME: https://github.com/rust-lang-nursery/packed_simd/issues/14
-        impl_unary!(f32x2[f32; 2]: tanh_f32);
-        impl_unary!(f32x4[f32; 4]: tanh_f32);
-        impl_unary!(f32x8[f32; 8]: tanh_f32);
-        impl_unary!(f32x16[f32; 16]: tanh_f32);
-
-        impl_unary!(f64x2[f64; 2]: tanh_f64);
-        impl_unary!(f64x4[f64; 4]: tanh_f64);
-        impl_unary!(f64x8[f64; 8]: tanh_f64);
-    } else if #[cfg(all(target_arch = "x86_64", feature = "sleef-sys"))] {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        feature = "sleef-sys",
+        not(feature = "deterministic_math"),
+    ))] {
         use sleef_sys::*;
         cfg_if! {
             if #[cfg(target_feature = "avx2")] {
@@ -94,18 +99,18 @@ cfg_if! {
                 impl_unary!(f64x2: Sleef_tanhd2_u10sse4);
                 impl_unary!(f64x4[h => f64x2]: Sleef_tanhd2_u10sse4);
             } else {
-                impl_unary!(f32x2[f32; 2]: tanh_f32);
-                impl_unary!(f32x16: tanh_v16f32);
-                impl_unary!(f64x8: tanh_v8f64);
-
+                impl_unary!(f32x2: tanh_v2f32);
                 impl_unary!(f32x4: tanh_v4f32);
                 impl_unary!(f32x8: tanh_v8f32);
+                impl_unary!(f32x16: tanh_v16f32);
+
                 impl_unary!(f64x2: tanh_v2f64);
                 impl_unary!(f64x4: tanh_v4f64);
+                impl_unary!(f64x8: tanh_v8f64);
             }
         }
     } else {
-        impl_unary!(f32x2[f32; 2]: tanh_f32);
+        impl_unary!(f32x2: tanh_v2f32);
         impl_unary!(f32x4: tanh_v4f32);
         impl_unary!(f32x8: tanh_v8f32);
         impl_unary!(f32x16: tanh_v16f32);
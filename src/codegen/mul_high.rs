@@ -0,0 +1,133 @@
+//! Vertical high-half integer multiplication.
+#[rustfmt::skip]
+
+use crate::*;
+
+crate trait MulHigh {
+    fn mul_high(self, y: Self) -> Self;
+}
+
+macro_rules! impl_mul_high {
+    // Multiply in a vector type with lanes twice as wide and keep the upper
+    // half. LLVM recognizes this pattern and lowers it to `pmulh{u}w` on x86,
+    // `{s,u}mull{2}` + narrowing on aarch64, etc.
+    (widen: $id:ident, $wide_id:ident, $bits:expr) => {
+        impl MulHigh for $id {
+            #[inline]
+            fn mul_high(self, y: Self) -> Self {
+                let a: $wide_id = self.cast();
+                let b: $wide_id = y.cast();
+                ((a * b) >> $bits).cast()
+            }
+        }
+    };
+    // No vector type with wider lanes is available: split each lane into
+    // two halves and compute the upper half of the product from the four
+    // partial products (Hacker's Delight, 8-2).
+    (split: $uid:ident, $iid:ident, $bits:expr) => {
+        impl MulHigh for $uid {
+            #[inline]
+            fn mul_high(self, y: Self) -> Self {
+                const H: u32 = $bits / 2;
+                let m = $uid::splat(!0 >> H);
+                let (a0, a1) = (self & m, self >> H);
+                let (b0, b1) = (y & m, y >> H);
+                let t = a1 * b0 + ((a0 * b0) >> H);
+                let w = (t & m) + a0 * b1;
+                a1 * b1 + (t >> H) + (w >> H)
+            }
+        }
+        impl MulHigh for $iid {
+            #[inline]
+            fn mul_high(self, y: Self) -> Self {
+                let a: $uid = self.cast();
+                let b: $uid = y.cast();
+                // The signed high half differs from the unsigned one by the
+                // other operand for each negative operand:
+                let ca: $uid = (self >> ($bits - 1)).cast();
+                let cb: $uid = (y >> ($bits - 1)).cast();
+                (MulHigh::mul_high(a, b) - (ca & b) - (cb & a)).cast()
+            }
+        }
+    };
+    (sized: $usize:ident, $isize:ident, $uty:ident, $ity:ident) => {
+        impl MulHigh for $usize {
+            #[inline]
+            fn mul_high(self, y: Self) -> Self {
+                let a: $uty = self.cast();
+                let b: $uty = y.cast();
+                MulHigh::mul_high(a, b).cast()
+            }
+        }
+        impl MulHigh for $isize {
+            #[inline]
+            fn mul_high(self, y: Self) -> Self {
+                let a: $ity = self.cast();
+                let b: $ity = y.cast();
+                MulHigh::mul_high(a, b).cast()
+            }
+        }
+    };
+}
+
+impl_mul_high! { widen: u8x2, u16x2, 8 }
+impl_mul_high! { widen: i8x2, i16x2, 8 }
+impl_mul_high! { widen: u8x4, u16x4, 8 }
+impl_mul_high! { widen: i8x4, i16x4, 8 }
+impl_mul_high! { widen: u8x8, u16x8, 8 }
+impl_mul_high! { widen: i8x8, i16x8, 8 }
+impl_mul_high! { widen: u8x16, u16x16, 8 }
+impl_mul_high! { widen: i8x16, i16x16, 8 }
+impl_mul_high! { widen: u8x32, u16x32, 8 }
+impl_mul_high! { widen: i8x32, i16x32, 8 }
+impl_mul_high! { split: u8x64, i8x64, 8 }
+
+impl_mul_high! { widen: u16x2, u32x2, 16 }
+impl_mul_high! { widen: i16x2, i32x2, 16 }
+impl_mul_high! { widen: u16x4, u32x4, 16 }
+impl_mul_high! { widen: i16x4, i32x4, 16 }
+impl_mul_high! { widen: u16x8, u32x8, 16 }
+impl_mul_high! { widen: i16x8, i32x8, 16 }
+impl_mul_high! { widen: u16x16, u32x16, 16 }
+impl_mul_high! { widen: i16x16, i32x16, 16 }
+impl_mul_high! { split: u16x32, i16x32, 16 }
+
+impl_mul_high! { widen: u32x2, u64x2, 32 }
+impl_mul_high! { widen: i32x2, i64x2, 32 }
+impl_mul_high! { widen: u32x4, u64x4, 32 }
+impl_mul_high! { widen: i32x4, i64x4, 32 }
+impl_mul_high! { widen: u32x8, u64x8, 32 }
+impl_mul_high! { widen: i32x8, i64x8, 32 }
+impl_mul_high! { split: u32x16, i32x16, 32 }
+
+// There are no 128-bit integer vector multiplies on any target, so the 64-bit
+// lanes always use the split algorithm on top of 64-bit multiplies.
+impl_mul_high! { split: u64x2, i64x2, 64 }
+impl_mul_high! { split: u64x4, i64x4, 64 }
+impl_mul_high! { split: u64x8, i64x8, 64 }
+
+impl_mul_high! { split: u128x1, i128x1, 128 }
+impl_mul_high! { split: u128x2, i128x2, 128 }
+impl_mul_high! { split: u128x4, i128x4, 128 }
+
+cfg_if! {
+    if #[cfg(target_pointer_width = "8")] {
+        impl_mul_high! { sized: usizex2, isizex2, u8x2, i8x2 }
+        impl_mul_high! { sized: usizex4, isizex4, u8x4, i8x4 }
+        impl_mul_high! { sized: usizex8, isizex8, u8x8, i8x8 }
+    } else if #[cfg(target_pointer_width = "16")] {
+        impl_mul_high! { sized: usizex2, isizex2, u16x2, i16x2 }
+        impl_mul_high! { sized: usizex4, isizex4, u16x4, i16x4 }
+        impl_mul_high! { sized: usizex8, isizex8, u16x8, i16x8 }
+    } else if #[cfg(target_pointer_width = "32")] {
+        impl_mul_high! { sized: usizex2, isizex2, u32x2, i32x2 }
+        impl_mul_high! { sized: usizex4, isizex4, u32x4, i32x4 }
+        impl_mul_high! { sized: usizex8, isizex8, u32x8, i32x8 }
+    } else if #[cfg(target_pointer_width = "64")] {
+        impl_mul_high! { sized: usizex2, isizex2, u64x2, i64x2 }
+        impl_mul_high! { sized: usizex4, isizex4, u64x4, i64x4 }
+        impl_mul_high! { sized: usizex8, isizex8, u64x8, i64x8 }
+    } else {
+        compile_error!("unsupported target_pointer_width");
+    }
+}
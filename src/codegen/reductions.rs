@@ -1 +1,2 @@
 crate mod mask;
+crate mod sad;
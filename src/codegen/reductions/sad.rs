@@ -0,0 +1,93 @@
+//! Code generation for the sum of absolute differences reduction.
+#![allow(unused_macros)]
+
+use crate::*;
+
+crate trait Sad {
+    type Output;
+    fn sad(self, y: Self) -> Self::Output;
+}
+
+macro_rules! impl_sad {
+    // Portable implementation: reinterpret the absolute differences as
+    // `u64` lanes and sum the 8 bytes of each lane by adding adjacent bit
+    // fields in place. The sums are exact, so this produces the same result
+    // as `psadbw`, and the order of the bytes within a lane does not matter.
+    (fallback: $id:ident, $out:ident) => {
+        impl Sad for $id {
+            type Output = $out;
+            #[inline]
+            fn sad(self, y: Self) -> $out {
+                const M8: u64 = 0x00ff_00ff_00ff_00ff;
+                const M16: u64 = 0x0000_ffff_0000_ffff;
+                const M32: u64 = 0x0000_0000_ffff_ffff;
+                let d: $out = unsafe { crate::mem::transmute(self.abs_diff(y)) };
+                let d = (d & $out::splat(M8)) + ((d >> 8) & $out::splat(M8));
+                let d = (d & $out::splat(M16)) + ((d >> 16) & $out::splat(M16));
+                (d & $out::splat(M32)) + (d >> 32)
+            }
+        }
+    };
+    (sse2: $id:ident, $out:ident) => {
+        impl Sad for $id {
+            type Output = $out;
+            #[inline]
+            fn sad(self, y: Self) -> $out {
+                #[cfg(target_arch = "x86")]
+                use crate::arch::x86::_mm_sad_epu8;
+                #[cfg(target_arch = "x86_64")]
+                use crate::arch::x86_64::_mm_sad_epu8;
+                use crate::mem::transmute;
+                unsafe { transmute(_mm_sad_epu8(transmute(self), transmute(y))) }
+            }
+        }
+    };
+    (avx2: $id:ident, $out:ident) => {
+        impl Sad for $id {
+            type Output = $out;
+            #[inline]
+            fn sad(self, y: Self) -> $out {
+                #[cfg(target_arch = "x86")]
+                use crate::arch::x86::_mm256_sad_epu8;
+                #[cfg(target_arch = "x86_64")]
+                use crate::arch::x86_64::_mm256_sad_epu8;
+                use crate::mem::transmute;
+                unsafe { transmute(_mm256_sad_epu8(transmute(self), transmute(y))) }
+            }
+        }
+    };
+    // Reduces each half of the vector separately:
+    (halves: $id:ident, $out:ident, $half_id:ident, $half_out:ident) => {
+        impl Sad for $id {
+            type Output = $out;
+            #[inline]
+            fn sad(self, y: Self) -> $out {
+                unsafe {
+                    use crate::mem::transmute;
+                    let x: [$half_id; 2] = transmute(self);
+                    let y: [$half_id; 2] = transmute(y);
+                    let r: [$half_out; 2] = [Sad::sad(x[0], y[0]), Sad::sad(x[1], y[1])];
+                    transmute(r)
+                }
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                 target_feature = "avx2"))] {
+        impl_sad!(sse2: u8x16, u64x2);
+        impl_sad!(avx2: u8x32, u64x4);
+        impl_sad!(halves: u8x64, u64x8, u8x32, u64x4);
+    } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = "sse2"))] {
+        impl_sad!(sse2: u8x16, u64x2);
+        impl_sad!(halves: u8x32, u64x4, u8x16, u64x2);
+        impl_sad!(halves: u8x64, u64x8, u8x32, u64x4);
+    } else {
+        impl_sad!(fallback: u8x16, u64x2);
+        impl_sad!(fallback: u8x32, u64x4);
+        impl_sad!(fallback: u8x64, u64x8);
+    }
+}
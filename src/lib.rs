@@ -172,6 +172,11 @@
 //!   use `unsafe { crate::mem::transmute(x) }` for that, making sure that the
 //!   value in the `u8x8` is a valid bit-pattern of `m8x8`.
 //!
+//!   Independently of this feature, the floating-point vectors provide the
+//!   inherent `to_bits`/`from_bits_u` methods, like `f32::to_bits` and
+//!   `f32::from_bits`, which convert to and from the unsigned integer vectors
+//!   with the same lane width and count (e.g. `f32x4` <-> `u32x4`).
+//!
 //! * **numeric casts** (`as`): are peformed using [`FromCast`]/[`Cast`]
 //! (`x.cast()`), just like `as`:
 //!
@@ -199,6 +204,25 @@
 //!   Numeric casts are not very "precise": sometimes lossy, sometimes value
 //!   preserving, etc.
 //!
+//! * **float-to-integer conversions**: [`FromFloat`]/[`ToInt`]
+//!   (`x.to_int(mode)`) round the floats with an explicit [`RoundingMode`],
+//!   and saturate like `as`: NaNs are converted to `0` and the out-of-range
+//!   values to `MIN` or `MAX`. The `unsafe` `to_int_unchecked` variant skips
+//!   these checks.
+//!
+//! * **saturating integer conversions**: [`FromSaturatingCast`]/
+//!   [`SaturatingCast`] (`x.saturating_cast()`) clamp the lanes to the range
+//!   of the target integer type instead of truncating them, and
+//!   [`FromPack`]/[`Pack`] (`a.pack(b)`) narrow two vectors into one with
+//!   twice as many lanes.
+//!
+//! * **widening conversions**: `x.widen_low()` and `x.widen_high()` cast the
+//!   low and high halves of the lanes to lanes twice as wide, e.g. `u8x16` to
+//!   `u16x8`. Vectors, including masks and pointers, can also be split into
+//!   their halves with `x.split()`, and two halves are concatenated with
+//!   `concat(lo, hi)`. Vectors of one lane are only available for 128-bit
+//!   lanes, so the other vectors of two lanes are split into their lanes.
+//!
 //! ## Hardware Features
 //!
 //! This crate can use different hardware features based on your configured
@@ -301,6 +325,9 @@ impl<A: sealed::SimdArray> sealed::Seal for Simd<A> {}
 
 /// Wrapper over `T` implementing a lexicoraphical order via the `PartialOrd`
 /// and/or `Ord` traits.
+///
+/// The lanes of floating-point vectors are compared in the IEEE 754 total
+/// order, so that these implement `Ord` as well.
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug)]
 #[allow(clippy::missing_inline_in_public_items)]
@@ -334,6 +361,7 @@ mod vPtr;
 pub use self::vPtr::*;
 
 pub use self::api::cast::*;
+pub use self::api::divisor::*;
 
 #[cfg(feature = "into_bits")]
 pub use self::api::into_bits::*;
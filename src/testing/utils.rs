@@ -128,3 +128,121 @@ macro_rules! ptr_vals {
         }
     };
 }
+
+/// Distance between two floating-point numbers in units in the last place.
+pub trait UlpDiff {
+    /// Returns the number of representable values between `self` and `other`.
+    ///
+    /// NaNs are equal to each other, `-0.0` is equal to `+0.0`, and the
+    /// distance between a NaN and a number is `u64::max_value()`.
+    fn ulp_diff(self, other: Self) -> u64;
+}
+
+macro_rules! impl_ulp_diff {
+    ($ty:ident, $ity:ident) => {
+        impl UlpDiff for $ty {
+            fn ulp_diff(self, other: Self) -> u64 {
+                // Maps the bits of `x` to integers with the same order as `x`:
+                fn ordered(x: $ty) -> i128 {
+                    let i = x.to_bits() as $ity;
+                    (if i < 0 { $ity::min_value().wrapping_sub(i) } else { i }) as i128
+                }
+                match (self.is_nan(), other.is_nan()) {
+                    (true, true) => 0,
+                    (false, false) => (ordered(self) - ordered(other)).abs() as u64,
+                    _ => u64::max_value(),
+                }
+            }
+        }
+    };
+}
+
+impl_ulp_diff!(f32, i32);
+impl_ulp_diff!(f64, i64);
+
+/// Bitwise equality of floating-point numbers.
+pub trait BitEq {
+    /// Returns `true` if `self` and `other` have the same bits, or if both
+    /// are NaNs, whose sign and payload are not compared.
+    fn bit_eq(self, other: Self) -> bool;
+}
+
+macro_rules! impl_bit_eq {
+    ($($ty:ident),*) => {
+        $(
+            impl BitEq for $ty {
+                fn bit_eq(self, other: Self) -> bool {
+                    self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_eq!(f32, f64);
+
+// Evaluates the scalar `libm` function `$fun` for `$elem_ty` arguments, e.g.,
+// `libm_ref!(f32: asin(x))` expands to `libm::asinf(x)`.
+#[allow(unused)]
+macro_rules! libm_ref {
+    (f32: $fun:ident($($arg:expr),*)) => {
+        paste::expr! { libm::[<$fun f>]($($arg),*) }
+    };
+    (f64: $fun:ident($($arg:expr),*)) => {
+        libm::$fun($($arg),*)
+    };
+}
+
+// Asserts that the method `$fun` of the vector type `$id` is within `$ulp`
+// ULP of the `libm` function `$fun` for all the values yielded by
+// `$values`. Each value is tested in a different lane. If the `libm`
+// function is named differently, it can be passed as `$fun => $libm_fun`.
+#[allow(unused)]
+macro_rules! test_ulp_unary {
+    ($id:ident[$elem_ty:ident]: $fun:ident, $values:expr, $ulp:expr) => {
+        test_ulp_unary!($id[$elem_ty]: $fun => $fun, $values, $ulp)
+    };
+    ($id:ident[$elem_ty:ident]: $fun:ident => $libm_fun:ident,
+     $values:expr, $ulp:expr) => {{
+        use crate::testing::utils::UlpDiff;
+        let mut x = $id::splat(0.);
+        for (k, v) in $values.into_iter().enumerate() {
+            let i = k % $id::lanes();
+            x = x.replace(i, v);
+            let r = x.$fun().extract(i);
+            let e = libm_ref!($elem_ty: $libm_fun(v));
+            assert!(
+                r.ulp_diff(e) <= $ulp,
+                "{}({:e}) = {:e}, expected {:e}",
+                stringify!($fun), v, r, e
+            );
+        }
+    }};
+}
+
+// Binary version of `test_ulp_unary!`: `$values` yields `(a, b)` pairs and
+// `a.$fun(b)` is tested against `libm`'s `$fun(a, b)` (or `$libm_fun(a, b)`).
+#[allow(unused)]
+macro_rules! test_ulp_binary {
+    ($id:ident[$elem_ty:ident]: $fun:ident, $values:expr, $ulp:expr) => {
+        test_ulp_binary!($id[$elem_ty]: $fun => $fun, $values, $ulp)
+    };
+    ($id:ident[$elem_ty:ident]: $fun:ident => $libm_fun:ident,
+     $values:expr, $ulp:expr) => {{
+        use crate::testing::utils::UlpDiff;
+        let mut a = $id::splat(0.);
+        let mut b = $id::splat(0.);
+        for (k, (u, v)) in $values.into_iter().enumerate() {
+            let i = k % $id::lanes();
+            a = a.replace(i, u);
+            b = b.replace(i, v);
+            let r = a.$fun(b).extract(i);
+            let e = libm_ref!($elem_ty: $libm_fun(u, v));
+            assert!(
+                r.ulp_diff(e) <= $ulp,
+                "{}({:e}, {:e}) = {:e}, expected {:e}",
+                stringify!($fun), u, v, r, e
+            );
+        }
+    }};
+}
@@ -0,0 +1,424 @@
+//! Golden outputs of the floating-point math functions and of the `sum`
+//! and `product` reductions, which the `deterministic_math` feature must
+//! reproduce bit for bit on all targets.
+//!
+//! Each line of the corpora in `tests/deterministic_math/` is the name of a
+//! function followed by the bits of its inputs and of its outputs in
+//! hexadecimal. The reductions take as many inputs as the vector has lanes.
+//! NaNs only need to be NaNs: their sign and payload are not portable.
+//!
+//! The corpora are rebuilt by the ignored `generate_f32` and `generate_f64`
+//! tests:
+//!
+//! ```text
+//! cargo test --release --features deterministic_math \
+//!     --test deterministic_math generate -- --ignored
+//! ```
+//!
+//! They record the outputs of `f32x4` and `f64x2` after checking each of them
+//! against a reference: `libm` (evaluated in `f64` for `f32` lanes) or the
+//! definition of the function. An output must be equal to its reference if
+//! that is a zero, an infinity or a NaN, and otherwise within the documented
+//! error bound of the function, if any. The expected outputs of the
+//! reductions are those of a scalar evaluation in the same order.
+#![cfg(feature = "deterministic_math")]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+use packed_simd_2::*;
+
+// The functions of the corpora, by arity.
+macro_rules! functions {
+    ($id:ident: $unary:ident, $binary:ident, $ternary:ident) => {
+        let $unary: &[(&str, fn($id) -> $id)] = &[
+            ("abs", $id::abs),
+            ("acos", $id::acos),
+            ("acosh", $id::acosh),
+            ("asin", $id::asin),
+            ("asinh", $id::asinh),
+            ("atan", $id::atan),
+            ("atanh", $id::atanh),
+            ("cbrt", $id::cbrt),
+            ("ceil", $id::ceil),
+            ("cos", $id::cos),
+            ("cos_fast", $id::cos_fast),
+            ("cos_pi", $id::cos_pi),
+            ("cosh", $id::cosh),
+            ("erf", $id::erf),
+            ("erfc", $id::erfc),
+            ("exp", $id::exp),
+            ("exp_fast", $id::exp_fast),
+            ("exp2", $id::exp2),
+            ("exp10", $id::exp10),
+            ("exp_m1", $id::exp_m1),
+            ("floor", $id::floor),
+            ("fract", $id::fract),
+            ("lgamma", $id::lgamma),
+            ("ln", $id::ln),
+            ("ln_fast", $id::ln_fast),
+            ("log2", $id::log2),
+            ("log10", $id::log10),
+            ("ln_1p", $id::ln_1p),
+            ("recpre", $id::recpre),
+            ("round", $id::round),
+            ("round_ties_even", $id::round_ties_even),
+            ("rsqrte", $id::rsqrte),
+            ("sin", $id::sin),
+            ("sin_fast", $id::sin_fast),
+            ("sin_pi", $id::sin_pi),
+            ("sinh", $id::sinh),
+            ("sqrt", $id::sqrt),
+            ("sqrte", $id::sqrte),
+            ("tanh", $id::tanh),
+            ("tgamma", $id::tgamma),
+            ("trunc", $id::trunc),
+        ];
+        let $binary: &[(&str, fn($id, $id) -> $id)] =
+            &[("atan2", $id::atan2), ("hypot", $id::hypot), ("powf", $id::powf)];
+        let $ternary: &[(&str, fn($id, $id, $id) -> $id)] =
+            &[("mul_add", $id::mul_add), ("mul_adde", $id::mul_adde)];
+    };
+}
+
+macro_rules! golden {
+    ($id:ident[$elem_ty:ident, $bits_ty:ident]: $corpus:expr) => {
+        #[cfg_attr(not(target_arch = "wasm32"), test)]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $id() {
+            functions!($id: unary, binary, ternary);
+
+            let mut count = 0;
+            for line in include_str!($corpus).lines() {
+                let mut words = line.split_whitespace();
+                let name = words.next().unwrap();
+                let args: Vec<$elem_ty> = words
+                    .map(|w| $elem_ty::from_bits($bits_ty::from_str_radix(w, 16).unwrap()))
+                    .collect();
+                let x = |i: usize| $id::splat(args[i]);
+
+                let (outputs, expected) = match name {
+                    "sin_cos_pi" => {
+                        let (s, c) = x(0).sin_cos_pi();
+                        (vec![s, c], &args[1..])
+                    }
+                    "sum" | "product" => {
+                        if args.len() != $id::lanes() + 1 {
+                            continue;
+                        }
+                        let v = $id::from_slice_unaligned(&args[..$id::lanes()]);
+                        let r = if name == "sum" { v.sum() } else { v.product() };
+                        (vec![$id::splat(r)], &args[$id::lanes()..])
+                    }
+                    _ => {
+                        let r = if let Some(f) = unary.iter().find(|f| f.0 == name) {
+                            (f.1)(x(0))
+                        } else if let Some(f) = binary.iter().find(|f| f.0 == name) {
+                            (f.1)(x(0), x(1))
+                        } else if let Some(f) = ternary.iter().find(|f| f.0 == name) {
+                            (f.1)(x(0), x(1), x(2))
+                        } else {
+                            panic!("unknown function `{}`", name);
+                        };
+                        (vec![r], &args[args.len() - 1..])
+                    }
+                };
+
+                for (r, &e) in outputs.iter().zip(expected) {
+                    for i in 0..$id::lanes() {
+                        let r = r.extract(i);
+                        assert!(
+                            r.to_bits() == e.to_bits() || (r.is_nan() && e.is_nan()),
+                            "{}: `{}` returned {:e} ({:#x}) instead of {:e} ({:#x})",
+                            stringify!($id),
+                            line,
+                            r,
+                            r.to_bits(),
+                            e,
+                            e.to_bits()
+                        );
+                    }
+                }
+                count += 1;
+            }
+            assert!(count > 0);
+        }
+    };
+}
+
+// Rebuilds a corpus from the outputs of `$id`, see the module documentation.
+//
+// The `libm` functions are those that the results must be equal to, in the
+// precision of the lanes.
+macro_rules! generate {
+    ($name:ident, $id:ident[$elem_ty:ident, $bits_ty:ident]: $corpus:expr,
+     libm::{$cbrt:ident, $erf:ident, $erfc:ident, $tgamma:ident,
+            $lgamma:ident, $hypot:ident}) => {
+        #[cfg(not(target_arch = "wasm32"))]
+        #[test]
+        #[ignore]
+        #[allow(clippy::float_cmp)]
+        fn $name() {
+            use std::fmt::Write;
+            use std::$elem_ty::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+            use std::$elem_ty::{
+                EPSILON, INFINITY, MAX, MIN, MIN_POSITIVE, NAN, NEG_INFINITY,
+            };
+
+            functions!($id: unary, binary, ternary);
+
+            let digits = 2 * std::mem::size_of::<$elem_ty>();
+            let hex = |x: $elem_ty| format!("{:01$x}", x.to_bits(), digits);
+            let ulps = |a: $elem_ty, b: $elem_ty| {
+                let ord = |x: $elem_ty| {
+                    let m = (x.to_bits() & ($bits_ty::max_value() >> 1)) as i128;
+                    if x.is_sign_negative() { -m } else { m }
+                };
+                (ord(a) - ord(b)).abs()
+            };
+            // Checks the output `r` of `call` against the reference `e`.
+            // Without a documented error `bound` only references that are
+            // zeros, infinities or NaNs are compared.
+            let check = |call: String, r: $elem_ty, e: $elem_ty, bound: Option<i128>| {
+                let ok = if e == 0. || e.is_infinite() || e.is_nan() {
+                    r.to_bits() == e.to_bits() || (r.is_nan() && e.is_nan())
+                } else {
+                    bound.map_or(true, |b| !r.is_nan() && ulps(r, e) <= b)
+                };
+                assert!(
+                    ok,
+                    "{}: `{}` returned {:e} ({:#x}), which is not within {:?} ULP \
+                     of the reference {:e} ({:#x})",
+                    stringify!($id),
+                    call,
+                    r,
+                    r.to_bits(),
+                    bound,
+                    e,
+                    e.to_bits()
+                );
+            };
+
+            // `f(x)` evaluated in `f64`:
+            let f64_ = |f: fn(f64) -> f64, x: $elem_ty| f(x as f64) as $elem_ty;
+            let reference = |name: &str, x: $elem_ty| -> ($elem_ty, Option<i128>) {
+                match name {
+                    "abs" => (x.abs(), Some(0)),
+                    "acos" => (f64_(libm::acos, x), Some(4)),
+                    "acosh" => (f64_(libm::acosh, x), Some(4)),
+                    "asin" => (f64_(libm::asin, x), Some(4)),
+                    "asinh" => (f64_(libm::asinh, x), Some(4)),
+                    "atan" => (f64_(libm::atan, x), Some(4)),
+                    "atanh" => (f64_(libm::atanh, x), Some(4)),
+                    "cbrt" => (libm::$cbrt(x), Some(0)),
+                    "ceil" => (x.ceil(), Some(0)),
+                    "cos" => (f64_(libm::cos, x), Some(2)),
+                    "cos_fast" => (f64_(libm::cos, x), Some(4)),
+                    "cos_pi" => (f64_(|x| libm::cos(x * std::f64::consts::PI), x), None),
+                    "cosh" => (f64_(libm::cosh, x), Some(4)),
+                    "erf" => (libm::$erf(x), Some(0)),
+                    "erfc" => (libm::$erfc(x), Some(0)),
+                    "exp" => (f64_(libm::exp, x), Some(2)),
+                    "exp_fast" => (f64_(libm::exp, x), Some(4)),
+                    "exp2" => (f64_(libm::exp2, x), None),
+                    "exp10" => (f64_(libm::exp10, x), None),
+                    "exp_m1" => (f64_(libm::expm1, x), None),
+                    "floor" => (x.floor(), Some(0)),
+                    "fract" => (x - x.trunc(), Some(0)),
+                    "lgamma" => (libm::$lgamma(x), Some(0)),
+                    "ln" => (f64_(libm::log, x), Some(2)),
+                    "ln_fast" => (f64_(libm::log, x), Some(4)),
+                    "log2" => (f64_(libm::log2, x), None),
+                    "log10" => (f64_(libm::log10, x), None),
+                    "ln_1p" => (f64_(libm::log1p, x), None),
+                    "recpre" => (1. / x, None),
+                    "round" => (x.round(), Some(0)),
+                    "round_ties_even" => {
+                        let r = x.round();
+                        if (r - x).abs() == 0.5 {
+                            (2. * (x / 2.).round(), Some(0))
+                        } else {
+                            (r, Some(0))
+                        }
+                    }
+                    "rsqrte" => (1. / x.sqrt(), None),
+                    "sin" => (f64_(libm::sin, x), Some(2)),
+                    "sin_fast" => (f64_(libm::sin, x), Some(4)),
+                    "sin_pi" => (f64_(|x| libm::sin(x * std::f64::consts::PI), x), None),
+                    "sinh" => (f64_(libm::sinh, x), Some(4)),
+                    "sqrt" => (x.sqrt(), Some(0)),
+                    "sqrte" => (x.sqrt(), None),
+                    "tanh" => (f64_(libm::tanh, x), Some(2)),
+                    "tgamma" => (libm::$tgamma(x), Some(0)),
+                    "trunc" => (x.trunc(), Some(0)),
+                    _ => panic!("no reference for `{}`", name),
+                }
+            };
+
+            let mut out = String::new();
+
+            let mut state = 0x2545_f491_4f6c_dd1d_u64;
+            // A xorshift generator, and a uniform value in [0, 1) from its
+            // state:
+            let mut next = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+            let uniform = |s: u64| (s >> 11) as f64 / (1_u64 << 53) as f64;
+
+            let mut inputs: Vec<$elem_ty> = vec![
+                0., -0., 1., -1., 0.5, -0.5, 2., -2., 3., -3.5,
+                INFINITY, NEG_INFINITY, NAN,
+                MIN_POSITIVE, -MIN_POSITIVE, MIN_POSITIVE / 8.,
+                MAX, MIN, EPSILON,
+                1e-30, -1e-7, 1e-3, -0.05, 0.1, -0.3, 0.75, -0.999, 0.999,
+                1.001, 1.25, -1.75, 6.5, 10., -12.5, 30., 55.5, -80., 100.,
+                700., -1000., 1e4, -1e6, 1e10, 1e30,
+                PI, FRAC_PI_2, FRAC_PI_4, E,
+            ];
+            // Magnitudes in [e^-10, e^10):
+            for _ in 0..16 {
+                let s = next();
+                let m = (20. * uniform(s) - 10.).exp();
+                inputs.push((if s & 1 == 0 { m } else { -m }) as $elem_ty);
+            }
+
+            for (name, f) in unary {
+                for &x in &inputs {
+                    let r = f($id::splat(x)).extract(0);
+                    let (e, bound) = reference(*name, x);
+                    check(format!("{}({:e})", name, x), r, e, bound);
+                    writeln!(out, "{} {} {}", name, hex(x), hex(r)).unwrap();
+                }
+            }
+            for &x in &inputs {
+                let (s, c) = $id::splat(x).sin_cos_pi();
+                let (s, c) = (s.extract(0), c.extract(0));
+                let call = format!("sin_cos_pi({:e})", x);
+                let (e, bound) = reference("sin_pi", x);
+                check(call.clone(), s, e, bound);
+                let (e, bound) = reference("cos_pi", x);
+                check(call, c, e, bound);
+                writeln!(out, "sin_cos_pi {} {} {}", hex(x), hex(s), hex(c)).unwrap();
+            }
+
+            let inputs2: &[$elem_ty] = &[
+                0., -0., 1., -1., 0.5, 2., -2.5, 3., 10., -0.1, 1e-3, 100.,
+                INFINITY, NEG_INFINITY, NAN, MIN_POSITIVE,
+            ];
+            for (name, f) in binary {
+                for &x in inputs2 {
+                    for &y in inputs2 {
+                        let r = f($id::splat(x), $id::splat(y)).extract(0);
+                        let (e, bound) = match *name {
+                            "atan2" => (libm::atan2(x as f64, y as f64) as $elem_ty, Some(4)),
+                            "hypot" => (libm::$hypot(x, y), Some(0)),
+                            "powf" => (libm::pow(x as f64, y as f64) as $elem_ty, Some(2)),
+                            _ => panic!("no reference for `{}`", name),
+                        };
+                        check(format!("{}({:e}, {:e})", name, x, y), r, e, bound);
+                        writeln!(out, "{} {} {} {}", name, hex(x), hex(y), hex(r)).unwrap();
+                    }
+                }
+            }
+
+            let one_eps = 1. + EPSILON;
+            let one_meps = 1. - EPSILON;
+            let mut inputs3: Vec<($elem_ty, $elem_ty, $elem_ty)> = vec![
+                (one_eps, one_meps, -1.), (one_meps, one_meps, -one_meps),
+                (0.1, 10., -1.), (1.5, 2.5, 0.25), (-0., 0., -0.), (0., -0., 0.),
+                (MAX, 2., -MAX), (MIN_POSITIVE, 0.5, 0.),
+                (INFINITY, 0., 1.), (NAN, 1., 1.), (3., 1. / 3., -1.),
+            ];
+            for i in 0..21 {
+                let n = inputs.len();
+                inputs3.push((
+                    inputs[i * 3 % n],
+                    inputs[(i * 7 + 5) % n],
+                    inputs[(i * 11 + 13) % n],
+                ));
+            }
+            for (name, f) in ternary {
+                for &(x, y, z) in &inputs3 {
+                    let r = f($id::splat(x), $id::splat(y), $id::splat(z)).extract(0);
+                    // `mul_adde` is at worst a multiplication followed by an
+                    // addition:
+                    let bound = if *name == "mul_add" { Some(0) } else { None };
+                    let call = format!("{}({:e}, {:e}, {:e})", name, x, y, z);
+                    check(call, r, x.mul_add(y, z), bound);
+                    writeln!(
+                        out,
+                        "{} {} {} {} {}",
+                        name,
+                        hex(x),
+                        hex(y),
+                        hex(z),
+                        hex(r)
+                    )
+                    .unwrap();
+                }
+            }
+
+            // The reductions of vectors of 2 lanes up to 512 bits:
+            let mut lanes = 2;
+            while lanes * digits * 4 <= 512 {
+                let n = lanes;
+                let mut vs: Vec<Vec<$elem_ty>> = vec![
+                    vec![-0.; n],
+                    vec![1.; n],
+                    (0..n)
+                        .map(|i| {
+                            (if i % 2 == 0 { 1e3 } else { -1e-3 }) * (i as $elem_ty + 1.)
+                        })
+                        .collect(),
+                    (0..n).map(|i| inputs[(i * 5 + 3) % inputs.len()]).collect(),
+                ];
+                for k in 0..4 {
+                    let scale = 10_f64.powi(k * 3);
+                    vs.push(
+                        (0..n)
+                            .map(|_| ((2. * uniform(next()) - 1.) * scale) as $elem_ty)
+                            .collect(),
+                    );
+                }
+                vs.push((0..n).map(|i| 1. + (i as $elem_ty) * 0.1).collect());
+                for v in &vs {
+                    let ops: &[(&str, fn($elem_ty, $elem_ty) -> $elem_ty)] =
+                        &[("sum", |a, b| a + b), ("product", |a, b| a * b)];
+                    for (name, op) in ops {
+                        // Pairs of adjacent lanes are reduced first:
+                        let mut x = v.clone();
+                        let mut m = n;
+                        while m > 1 {
+                            m /= 2;
+                            for i in 0..m {
+                                x[i] = op(x[2 * i], x[2 * i + 1]);
+                            }
+                        }
+                        let v: Vec<String> = v.iter().map(|&y| hex(y)).collect();
+                        writeln!(out, "{} {} {}", name, v.join(" "), hex(x[0])).unwrap();
+                    }
+                }
+                lanes *= 2;
+            }
+
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/", $corpus);
+            std::fs::write(path, out).unwrap();
+        }
+    };
+}
+
+golden!(f32x2[f32, u32]: "deterministic_math/f32.txt");
+golden!(f32x4[f32, u32]: "deterministic_math/f32.txt");
+golden!(f32x8[f32, u32]: "deterministic_math/f32.txt");
+golden!(f32x16[f32, u32]: "deterministic_math/f32.txt");
+golden!(f64x2[f64, u64]: "deterministic_math/f64.txt");
+golden!(f64x4[f64, u64]: "deterministic_math/f64.txt");
+golden!(f64x8[f64, u64]: "deterministic_math/f64.txt");
+
+generate!(generate_f32, f32x4[f32, u32]: "deterministic_math/f32.txt",
+          libm::{cbrtf, erff, erfcf, tgammaf, lgammaf, hypotf});
+generate!(generate_f64, f64x2[f64, u64]: "deterministic_math/f64.txt",
+          libm::{cbrt, erf, erfc, tgamma, lgamma, hypot});
@@ -0,0 +1,107 @@
+//! Exhaustive tests of the error bounds of the `f32` math functions: every
+//! `f32` is compared against the `f64` function of `std` rounded to `f32`.
+//!
+//! These take a few minutes in release mode and are ignored by default:
+//!
+//! ```text
+//! cargo test --release --test exhaustive_f32_math -- --ignored
+//! ```
+#![cfg(not(target_arch = "wasm32"))]
+
+use packed_simd_2::*;
+use std::mem;
+
+fn ulp_diff(a: f32, b: f32) -> u64 {
+    if a.is_nan() && b.is_nan() {
+        return 0;
+    }
+    if a.is_nan() || b.is_nan() {
+        return u64::max_value();
+    }
+    let ord = |x: f32| {
+        let i = i64::from(x.to_bits() as i32);
+        if i < 0 {
+            -(i & 0x7fff_ffff)
+        } else {
+            i
+        }
+    };
+    (ord(a) - ord(b)).abs() as u64
+}
+
+fn check<F, R>(name: &str, f: F, reference: R, ulp: u64)
+where
+    F: Fn(f32x16) -> f32x16,
+    R: Fn(f64) -> f64,
+{
+    #[rustfmt::skip]
+    let offsets = u32x16::new(
+        0, 1, 2, 3, 4, 5, 6, 7,
+        8, 9, 10, 11, 12, 13, 14, 15,
+    );
+    for chunk in 0..=(u32::max_value() / 16) {
+        let bits = u32x16::splat(chunk * 16) + offsets;
+        let x: f32x16 = unsafe { mem::transmute(bits) };
+        let r = f(x);
+        for i in 0..f32x16::lanes() {
+            let (x, r) = (x.extract(i), r.extract(i));
+            let e = reference(f64::from(x)) as f32;
+            assert!(
+                ulp_diff(r, e) <= ulp,
+                "{}({:e}) = {:e}, expected {:e}",
+                name,
+                x,
+                r,
+                e
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore]
+fn sin() {
+    check("sin", f32x16::sin, f64::sin, 2);
+}
+
+#[test]
+#[ignore]
+fn sin_fast() {
+    check("sin_fast", f32x16::sin_fast, f64::sin, 4);
+}
+
+#[test]
+#[ignore]
+fn cos() {
+    check("cos", f32x16::cos, f64::cos, 2);
+}
+
+#[test]
+#[ignore]
+fn cos_fast() {
+    check("cos_fast", f32x16::cos_fast, f64::cos, 4);
+}
+
+#[test]
+#[ignore]
+fn exp() {
+    check("exp", f32x16::exp, f64::exp, 2);
+}
+
+#[test]
+#[ignore]
+fn exp_fast() {
+    check("exp_fast", f32x16::exp_fast, f64::exp, 4);
+}
+
+#[test]
+#[ignore]
+fn ln() {
+    check("ln", f32x16::ln, f64::ln, 2);
+}
+
+#[test]
+#[ignore]
+fn ln_fast() {
+    check("ln_fast", f32x16::ln_fast, f64::ln, 4);
+}